@@ -0,0 +1,229 @@
+use std::time::Duration;
+
+use http_body_util::Full;
+use hyper::{body::Bytes, Method, Request};
+use hyper_util::{
+    client::legacy::{
+        connect::{Connect, HttpConnector},
+        Builder, Client,
+    },
+    rt::{TokioExecutor, TokioTimer},
+};
+use reqores::{
+    ClientBackend, ClientRequest, HttpMethod, HttpStatusCodeParseError, RawClientRequest,
+    RawClientResponse,
+};
+
+use crate::client_response::HyperClientResponse;
+
+/// The connector [`HyperClient::new`] and [`HyperClientBuilder::build`] use,
+/// connecting to both `http://` and `https://` URLs with the `tls` feature.
+#[cfg(feature = "tls")]
+pub type DefaultConnector = hyper_tls::HttpsConnector<HttpConnector>;
+/// The connector [`HyperClient::new`] and [`HyperClientBuilder::build`] use,
+/// connecting to `http://` URLs only without the `tls` feature.
+#[cfg(not(feature = "tls"))]
+pub type DefaultConnector = HttpConnector;
+
+fn default_connector() -> DefaultConnector {
+    #[cfg(feature = "tls")]
+    return hyper_tls::HttpsConnector::new();
+    #[cfg(not(feature = "tls"))]
+    return HttpConnector::new();
+}
+
+/// The client sending requests over the pooled connections of [`hyper_util::client::legacy::Client`].
+///
+/// The client is cheap to clone, and the clones share the same pool.
+/// It must be used on the tokio runtime.
+#[derive(Clone)]
+pub struct HyperClient<C = DefaultConnector>(Client<C, Full<Bytes>>);
+
+impl Default for HyperClient {
+    fn default() -> Self {
+        HyperClient::new()
+    }
+}
+
+impl HyperClient {
+    pub fn new() -> Self {
+        HyperClientBuilder::new().build()
+    }
+}
+
+impl<C> HyperClient<C> {
+    pub fn with_client(client: Client<C, Full<Bytes>>) -> Self {
+        HyperClient(client)
+    }
+}
+
+/// The builder of [`HyperClient`], exposing the pool, keep-alive and HTTP/2 settings.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use reqores_client_hyper::HyperClientBuilder;
+///
+/// let client = HyperClientBuilder::new()
+///     .with_pool_max_idle_per_host(64)
+///     .with_pool_idle_timeout(Some(Duration::from_secs(30)))
+///     .with_http2_keep_alive_interval(Some(Duration::from_secs(10)))
+///     .build();
+/// ```
+#[derive(Clone, Debug)]
+pub struct HyperClientBuilder(Builder);
+
+impl Default for HyperClientBuilder {
+    fn default() -> Self {
+        let mut builder = Client::builder(TokioExecutor::new());
+        builder.timer(TokioTimer::new()).pool_timer(TokioTimer::new());
+        HyperClientBuilder(builder)
+    }
+}
+
+impl HyperClientBuilder {
+    /// Create a new [`HyperClientBuilder`] with the defaults of hyper, timed by the tokio runtime.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the maximum number of idle connections kept per host, defaults to no limit.
+    ///
+    /// Setting it to 0 disables keep-alive, opening a new connection for every request.
+    pub fn with_pool_max_idle_per_host(mut self, max_idle: usize) -> Self {
+        self.0.pool_max_idle_per_host(max_idle);
+        self
+    }
+
+    /// Set how long idle connections are kept, defaults to 90 seconds, where [`None`] keeps them forever.
+    pub fn with_pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.0.pool_idle_timeout(timeout);
+        self
+    }
+
+    /// Use HTTP/2 with prior knowledge for every connection, defaults to `false`.
+    pub fn with_http2_only(mut self, enabled: bool) -> Self {
+        self.0.http2_only(enabled);
+        self
+    }
+
+    /// Set the interval of HTTP/2 keep-alive pings, defaults to [`None`] sending no pings.
+    pub fn with_http2_keep_alive_interval(mut self, interval: Option<Duration>) -> Self {
+        self.0.http2_keep_alive_interval(interval);
+        self
+    }
+
+    /// Set how long to wait for the acknowledgement of HTTP/2 keep-alive pings, defaults to 20 seconds.
+    pub fn with_http2_keep_alive_timeout(mut self, timeout: Duration) -> Self {
+        self.0.http2_keep_alive_timeout(timeout);
+        self
+    }
+
+    /// Send HTTP/2 keep-alive pings even while no request is in flight, defaults to `false`.
+    pub fn with_http2_keep_alive_while_idle(mut self, enabled: bool) -> Self {
+        self.0.http2_keep_alive_while_idle(enabled);
+        self
+    }
+
+    /// Size the HTTP/2 flow control windows from the measured bandwidth, defaults to `false`.
+    pub fn with_http2_adaptive_window(mut self, enabled: bool) -> Self {
+        self.0.http2_adaptive_window(enabled);
+        self
+    }
+
+    /// Build the [`HyperClient`] with the [`DefaultConnector`].
+    pub fn build(&self) -> HyperClient {
+        self.build_with_connector(default_connector())
+    }
+
+    /// Build the [`HyperClient`] connecting with the connector.
+    pub fn build_with_connector<C>(&self, connector: C) -> HyperClient<C>
+    where
+        C: Connect + Clone,
+    {
+        HyperClient(self.0.build(connector))
+    }
+}
+
+impl<C> HyperClient<C>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    pub async fn call<Req: ClientRequest>(
+        &self,
+        client_request: Req,
+    ) -> Result<Req::Response, HyperClientError> {
+        let client_response = self.send(RawClientRequest::new(&client_request)).await?;
+
+        client_request
+            .deserialize(&client_response)
+            .map_err(HyperClientError::Deserialize)
+    }
+}
+
+impl<C> ClientBackend for HyperClient<C>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    type Error = HyperClientError;
+
+    async fn send(
+        &self,
+        raw_request: RawClientRequest,
+    ) -> Result<RawClientResponse, HyperClientError> {
+        let method = match raw_request.method {
+            HttpMethod::Get => Method::GET,
+            HttpMethod::Put => Method::PUT,
+            HttpMethod::Post => Method::POST,
+            HttpMethod::Delete => Method::DELETE,
+            HttpMethod::Patch => Method::PATCH,
+            HttpMethod::Head => Method::HEAD,
+            HttpMethod::Options => Method::OPTIONS,
+            HttpMethod::Connect => Method::CONNECT,
+            HttpMethod::Trace => Method::TRACE,
+        };
+        let mut request = Request::builder().method(method).uri(&raw_request.url);
+        for (k, v) in raw_request.headers {
+            request = request.header(k, v);
+        }
+        let body = Full::new(Bytes::from(raw_request.body.unwrap_or_default()));
+        let request = request.body(body).map_err(HyperClientError::Request)?;
+
+        let response = self
+            .0
+            .request(request)
+            .await
+            .map_err(HyperClientError::Client)?;
+        let client_response = HyperClientResponse::new(response).await?;
+
+        Ok(client_response.into_raw())
+    }
+}
+
+/// The error type for [`HyperClient`].
+#[derive(Debug)]
+pub enum HyperClientError {
+    /// The request could not be built, like with the invalid URL or header.
+    Request(hyper::http::Error),
+    /// The request failed to be sent or the response head failed to be received.
+    Client(hyper_util::client::legacy::Error),
+    /// The response body failed to be received.
+    Hyper(hyper::Error),
+    /// The response has the status [`reqores::HttpStatusCode`] does not know.
+    Status(HttpStatusCodeParseError),
+    /// The response failed to be deserialized.
+    Deserialize(String),
+}
+
+impl std::error::Error for HyperClientError {}
+
+impl core::fmt::Display for HyperClientError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            HyperClientError::Request(e) => write!(f, "Request Error: {}", e),
+            HyperClientError::Client(e) => write!(f, "Client Error: {}", e),
+            HyperClientError::Hyper(e) => write!(f, "Hyper Error: {}", e),
+            HyperClientError::Status(e) => write!(f, "Status Error: {}", e),
+            HyperClientError::Deserialize(e) => write!(f, "Deserialize Error: {}", e),
+        }
+    }
+}
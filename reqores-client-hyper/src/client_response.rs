@@ -0,0 +1,60 @@
+use http_body_util::BodyExt;
+use hyper::{body::Incoming, header::HeaderMap, Response};
+use reqores::{ClientResponse, HttpStatusCode, RawClientResponse};
+
+use crate::HyperClientError;
+
+pub struct HyperClientResponse {
+    body: Vec<u8>,
+    headers: HeaderMap,
+    status: HttpStatusCode,
+}
+
+impl HyperClientResponse {
+    pub async fn new(response: Response<Incoming>) -> Result<Self, HyperClientError> {
+        let status = HttpStatusCode::try_from(response.status().as_u16())
+            .map_err(HyperClientError::Status)?;
+        let (parts, body) = response.into_parts();
+        let body = body
+            .collect()
+            .await
+            .map_err(HyperClientError::Hyper)?
+            .to_bytes()
+            .to_vec();
+        Ok(Self {
+            body,
+            headers: parts.headers,
+            status,
+        })
+    }
+
+    pub fn into_raw(self) -> RawClientResponse {
+        let headers = self
+            .headers
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        RawClientResponse {
+            status: self.status,
+            headers,
+            body: self.body,
+        }
+    }
+}
+
+impl ClientResponse for HyperClientResponse {
+    fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    fn status(&self) -> HttpStatusCode {
+        self.status.clone()
+    }
+
+    fn header(&self, key: &str) -> Option<String> {
+        self.headers
+            .get(key)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string())
+    }
+}
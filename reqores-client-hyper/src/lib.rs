@@ -0,0 +1,4 @@
+pub use api_client::{DefaultConnector, HyperClient, HyperClientBuilder, HyperClientError};
+
+mod api_client;
+mod client_response;
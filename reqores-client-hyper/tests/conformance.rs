@@ -0,0 +1,54 @@
+use std::{future::Future, time::Duration};
+
+use reqores_client_hyper::{HyperClient, HyperClientBuilder};
+use reqores_test_support::conformance::Conformance;
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(future)
+}
+
+#[test]
+fn hyper_client_passes_conformance() {
+    // hyper never follows redirects.
+    block_on(async {
+        Conformance::new()
+            .run(&HyperClient::new())
+            .await
+            .unwrap_or_else(|report| panic!("{}", report));
+    });
+}
+
+#[test]
+fn tuned_hyper_client_passes_conformance() {
+    let client = HyperClientBuilder::new()
+        .with_pool_max_idle_per_host(4)
+        .with_pool_idle_timeout(Some(Duration::from_secs(5)))
+        .with_http2_keep_alive_interval(Some(Duration::from_secs(10)))
+        .with_http2_adaptive_window(true)
+        .build();
+
+    block_on(async {
+        Conformance::new()
+            .run(&client)
+            .await
+            .unwrap_or_else(|report| panic!("{}", report));
+    });
+}
+
+#[test]
+fn hyper_client_without_keep_alive_passes_conformance() {
+    let client = HyperClientBuilder::new()
+        .with_pool_max_idle_per_host(0)
+        .build();
+
+    block_on(async {
+        Conformance::new()
+            .run(&client)
+            .await
+            .unwrap_or_else(|report| panic!("{}", report));
+    });
+}
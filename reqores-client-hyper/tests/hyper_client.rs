@@ -0,0 +1,71 @@
+use std::future::Future;
+
+use hyper_util::client::legacy::connect::HttpConnector;
+use reqores::{ClientBackend, HttpMethod, HttpStatusCode, RawClientRequest, ServerResponseBuilder};
+use reqores_client_hyper::{HyperClientBuilder, HyperClientError};
+use reqores_test_support::stub::StubServerBuilder;
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(future)
+}
+
+fn request(url: impl Into<String>) -> RawClientRequest {
+    RawClientRequest {
+        method: HttpMethod::Get,
+        url: url.into(),
+        headers: Vec::new(),
+        body: None,
+    }
+}
+
+#[test]
+fn plain_connector_sends_requests() {
+    let server = StubServerBuilder::new()
+        .with_route(
+            HttpMethod::Get,
+            "/hello",
+            ServerResponseBuilder::new()
+                .with_status(HttpStatusCode::Accepted)
+                .body_str("Hello"),
+        )
+        .start()
+        .unwrap();
+    let client = HyperClientBuilder::new().build_with_connector(HttpConnector::new());
+
+    let responses = block_on(async {
+        let mut responses = Vec::new();
+        for _ in 0..3 {
+            responses.push(client.send(request(server.url("/hello"))).await.unwrap());
+        }
+        responses
+    });
+
+    for response in responses {
+        assert_eq!(response.status, HttpStatusCode::Accepted);
+        assert_eq!(response.body, b"Hello");
+    }
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[test]
+fn invalid_url_is_request_error() {
+    let client = HyperClientBuilder::new().build();
+
+    let result = block_on(client.send(request("not a url")));
+
+    assert!(matches!(result, Err(HyperClientError::Request(_))));
+}
+
+#[test]
+fn http2_only_fails_against_http1_server() {
+    let server = StubServerBuilder::new().start().unwrap();
+    let client = HyperClientBuilder::new().with_http2_only(true).build();
+
+    let result = block_on(client.send(request(server.url("/"))));
+
+    assert!(matches!(result, Err(HyperClientError::Client(_))));
+}
@@ -0,0 +1,248 @@
+use std::{fs, io, path::Path};
+
+use reqores::{HttpMethod, HttpStatusCode, RawClientRequest, RawClientResponse};
+use serde::{Deserialize, Serialize};
+
+/// The recorded interactions, stored as pretty-printed JSON to be reviewed in diffs.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Cassette {
+    /// The interactions in the order they happened.
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    /// Read the cassette from the file.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let content = fs::read(path)?;
+        serde_json::from_slice(&content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Write the cassette to the file.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut content = serde_json::to_vec_pretty(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        content.push(b'\n');
+        fs::write(path, content)
+    }
+}
+
+/// The request and the response sent and received together.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+    /// The request sent.
+    pub request: RecordedRequest,
+    /// The response received.
+    pub response: RecordedResponse,
+}
+
+/// The request stored in [`Cassette`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// The HTTP method, like "GET".
+    pub method: String,
+    /// The URL endpoint.
+    pub url: String,
+    /// The headers sent.
+    pub headers: Vec<(String, String)>,
+    /// The body sent.
+    pub body: Option<RecordedBody>,
+}
+
+impl RecordedRequest {
+    /// Whether the request is the same as the recorded one, ignoring headers.
+    pub fn matches(&self, other: &RecordedRequest) -> bool {
+        self.method == other.method && self.url == other.url && self.body == other.body
+    }
+}
+
+impl From<&RawClientRequest> for RecordedRequest {
+    fn from(request: &RawClientRequest) -> Self {
+        RecordedRequest {
+            method: request.method.to_string(),
+            url: request.url.clone(),
+            headers: request.headers.clone(),
+            body: request.body.as_deref().map(RecordedBody::from),
+        }
+    }
+}
+
+impl TryFrom<RecordedRequest> for RawClientRequest {
+    type Error = String;
+
+    fn try_from(request: RecordedRequest) -> Result<Self, Self::Error> {
+        Ok(RawClientRequest {
+            method: request
+                .method
+                .parse::<HttpMethod>()
+                .map_err(|e| e.to_string())?,
+            url: request.url,
+            headers: request.headers,
+            body: request.body.map(RecordedBody::into_bytes),
+        })
+    }
+}
+
+/// The response stored in [`Cassette`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+    /// The HTTP status code.
+    pub status: u16,
+    /// The headers received.
+    pub headers: Vec<(String, String)>,
+    /// The body received.
+    pub body: RecordedBody,
+}
+
+impl From<&RawClientResponse> for RecordedResponse {
+    fn from(response: &RawClientResponse) -> Self {
+        RecordedResponse {
+            status: u16::from(response.status.clone()),
+            headers: response.headers.clone(),
+            body: RecordedBody::from(response.body.as_slice()),
+        }
+    }
+}
+
+impl TryFrom<RecordedResponse> for RawClientResponse {
+    type Error = String;
+
+    fn try_from(response: RecordedResponse) -> Result<Self, Self::Error> {
+        Ok(RawClientResponse {
+            status: HttpStatusCode::try_from(response.status).map_err(|e| e.to_string())?,
+            headers: response.headers,
+            body: response.body.into_bytes(),
+        })
+    }
+}
+
+/// The body stored in [`Cassette`], kept as text when it is valid UTF-8.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecordedBody {
+    /// The UTF-8 body.
+    Text(String),
+    /// The binary body.
+    Bytes(Vec<u8>),
+}
+
+impl RecordedBody {
+    /// The body as bytes.
+    pub fn into_bytes(self) -> Vec<u8> {
+        match self {
+            RecordedBody::Text(text) => text.into_bytes(),
+            RecordedBody::Bytes(bytes) => bytes,
+        }
+    }
+}
+
+impl From<&[u8]> for RecordedBody {
+    fn from(body: &[u8]) -> Self {
+        match std::str::from_utf8(body) {
+            Ok(text) => RecordedBody::Text(text.to_string()),
+            Err(_) => RecordedBody::Bytes(body.to_vec()),
+        }
+    }
+}
+
+type Hook<T> = Box<dyn Fn(&mut T) + Send + Sync>;
+
+/// The redaction applied to interactions before they are recorded or matched.
+///
+/// Use the same redactor for [`RecordingClient`](`crate::RecordingClient`) and [`ReplayClient`](`crate::ReplayClient`)
+/// so that live requests match the redacted cassette.
+#[derive(Default)]
+pub struct Redactor {
+    headers: Vec<String>,
+    secrets: Vec<String>,
+    request_hooks: Vec<Hook<RecordedRequest>>,
+    response_hooks: Vec<Hook<RecordedResponse>>,
+}
+
+impl Redactor {
+    /// The placeholder replacing redacted values.
+    pub const REDACTED: &'static str = "[REDACTED]";
+
+    /// Create a new [`Redactor`] redacting nothing.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Redact the values of the header in both requests and responses.
+    pub fn header(mut self, name: impl Into<String>) -> Self {
+        self.headers.push(name.into());
+        self
+    }
+
+    /// Redact every occurrence of the secret in URLs, header values and text bodies.
+    pub fn secret(mut self, secret: impl Into<String>) -> Self {
+        let secret = secret.into();
+        if !secret.is_empty() {
+            self.secrets.push(secret);
+        }
+        self
+    }
+
+    /// Redact requests with the custom hook, run after the other redactions.
+    pub fn with_request_hook(
+        mut self,
+        hook: impl Fn(&mut RecordedRequest) + Send + Sync + 'static,
+    ) -> Self {
+        self.request_hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Redact responses with the custom hook, run after the other redactions.
+    pub fn with_response_hook(
+        mut self,
+        hook: impl Fn(&mut RecordedResponse) + Send + Sync + 'static,
+    ) -> Self {
+        self.response_hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Apply the redactions to the request.
+    pub fn redact_request(&self, request: &mut RecordedRequest) {
+        for secret in &self.secrets {
+            request.url = request.url.replace(secret, Redactor::REDACTED);
+        }
+        self.redact_headers(&mut request.headers);
+        if let Some(body) = &mut request.body {
+            self.redact_body(body);
+        }
+        for hook in &self.request_hooks {
+            hook(request);
+        }
+    }
+
+    /// Apply the redactions to the response.
+    pub fn redact_response(&self, response: &mut RecordedResponse) {
+        self.redact_headers(&mut response.headers);
+        self.redact_body(&mut response.body);
+        for hook in &self.response_hooks {
+            hook(response);
+        }
+    }
+
+    fn redact_headers(&self, headers: &mut [(String, String)]) {
+        for (name, value) in headers.iter_mut() {
+            if self
+                .headers
+                .iter()
+                .any(|redacted| redacted.eq_ignore_ascii_case(name))
+            {
+                *value = Redactor::REDACTED.to_string();
+            }
+            for secret in &self.secrets {
+                *value = value.replace(secret, Redactor::REDACTED);
+            }
+        }
+    }
+
+    fn redact_body(&self, body: &mut RecordedBody) {
+        if let RecordedBody::Text(text) = body {
+            for secret in &self.secrets {
+                *text = text.replace(secret, Redactor::REDACTED);
+            }
+        }
+    }
+}
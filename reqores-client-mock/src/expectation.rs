@@ -0,0 +1,181 @@
+use reqores::{HttpMethod, HttpStatusCode, RawClientRequest, RawClientResponse};
+use serde::Serialize;
+
+enum BodyMatcher {
+    Exact(Vec<u8>),
+    Json(serde_json::Value),
+}
+
+type Predicate = Box<dyn Fn(&RawClientRequest) -> bool + Send + Sync>;
+
+/// The request expected by [`MockClient`](`crate::MockClient`) and the response to return for it.
+///
+/// By default, the expectation should be met exactly once and responds with empty 200 OK.
+pub struct Expectation {
+    method: HttpMethod,
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Option<BodyMatcher>,
+    predicates: Vec<(String, Predicate)>,
+    response: RawClientResponse,
+    pub(crate) times: usize,
+    pub(crate) calls: usize,
+}
+
+impl Expectation {
+    /// Create a new [`Expectation`] for the method and the URL.
+    pub fn new(method: HttpMethod, url: impl Into<String>) -> Self {
+        Expectation {
+            method,
+            url: url.into(),
+            headers: Vec::new(),
+            body: None,
+            predicates: Vec::new(),
+            response: RawClientResponse {
+                status: HttpStatusCode::Ok,
+                headers: Vec::new(),
+                body: Vec::new(),
+            },
+            times: 1,
+            calls: 0,
+        }
+    }
+
+    /// Expect the header to have the value.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Expect the body to be exactly the bytes.
+    pub fn with_body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = Some(BodyMatcher::Exact(body.into()));
+        self
+    }
+
+    /// Expect the body to be JSON equal to the value, regardless of formatting.
+    pub fn with_json_body<T: Serialize>(mut self, body: &T) -> Self {
+        self.body = Some(BodyMatcher::Json(
+            serde_json::to_value(body).expect("the expected body should be serializable"),
+        ));
+        self
+    }
+
+    /// Expect the request to satisfy the predicate, described with the description in reports.
+    pub fn matching(
+        mut self,
+        description: impl Into<String>,
+        predicate: impl Fn(&RawClientRequest) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.predicates
+            .push((description.into(), Box::new(predicate)));
+        self
+    }
+
+    /// Expect the request to be sent exactly `times` times.
+    pub fn times(mut self, times: usize) -> Self {
+        self.times = times;
+        self
+    }
+
+    /// Set the status of the response.
+    pub fn with_response_status(mut self, status: HttpStatusCode) -> Self {
+        self.response.status = status;
+        self
+    }
+
+    /// Attach the header to the response.
+    pub fn with_response_header(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.response.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Set the body of the response.
+    pub fn with_response_body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.response.body = body.into();
+        self
+    }
+
+    /// Set the body of the response to the JSON serialized value.
+    pub fn with_response_json<T: Serialize>(self, body: &T) -> Self {
+        self.with_response_header("Content-Type", "application/json; charset=UTF-8")
+            .with_response_body(
+                serde_json::to_vec(body).expect("the response body should be serializable"),
+            )
+    }
+
+    pub(crate) fn is_exhausted(&self) -> bool {
+        self.calls >= self.times
+    }
+
+    pub(crate) fn response(&self) -> RawClientResponse {
+        self.response.clone()
+    }
+
+    pub(crate) fn describe(&self) -> String {
+        format!("{} {}", self.method, self.url)
+    }
+
+    /// The differences between the expectation and the request, empty if it matches.
+    pub(crate) fn mismatches(&self, request: &RawClientRequest) -> Vec<String> {
+        let mut mismatches = Vec::new();
+        if self.method != request.method {
+            mismatches.push(format!(
+                "method: expected {}, got {}",
+                self.method, request.method
+            ));
+        }
+        if self.url != request.url {
+            mismatches.push(format!(
+                "url: expected {:?}, got {:?}",
+                self.url, request.url
+            ));
+        }
+        for (name, value) in &self.headers {
+            let actual = request.header(name);
+            if actual != Some(value.as_str()) {
+                mismatches.push(format!(
+                    "header {:?}: expected {:?}, got {:?}",
+                    name, value, actual
+                ));
+            }
+        }
+        match &self.body {
+            Some(BodyMatcher::Exact(expected)) => {
+                let actual = request.body.as_deref().unwrap_or_default();
+                if expected.as_slice() != actual {
+                    mismatches.push(format!(
+                        "body: expected {:?}, got {:?}",
+                        String::from_utf8_lossy(expected),
+                        String::from_utf8_lossy(actual)
+                    ));
+                }
+            }
+            Some(BodyMatcher::Json(expected)) => {
+                let actual = request.body.as_deref().unwrap_or_default();
+                match serde_json::from_slice::<serde_json::Value>(actual) {
+                    Ok(actual) if &actual == expected => {}
+                    Ok(actual) => {
+                        mismatches.push(format!("json body: expected {}, got {}", expected, actual))
+                    }
+                    Err(_) => mismatches.push(format!(
+                        "json body: expected {}, got non-json {:?}",
+                        expected,
+                        String::from_utf8_lossy(actual)
+                    )),
+                }
+            }
+            None => {}
+        }
+        for (description, predicate) in &self.predicates {
+            if !predicate(request) {
+                mismatches.push(format!("predicate {:?} is not satisfied", description));
+            }
+        }
+        mismatches
+    }
+}
@@ -0,0 +1,13 @@
+pub use cassette::{
+    Cassette, Interaction, RecordedBody, RecordedRequest, RecordedResponse, Redactor,
+};
+pub use expectation::Expectation;
+pub use mock_client::{MockClient, UnmatchedRequest};
+pub use recording_client::{RecordingClient, RecordingError};
+pub use replay_client::{ReplayClient, ReplayError};
+
+mod cassette;
+mod expectation;
+mod mock_client;
+mod recording_client;
+mod replay_client;
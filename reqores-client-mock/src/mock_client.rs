@@ -0,0 +1,113 @@
+use std::sync::Mutex;
+
+use reqores::{ClientBackend, RawClientRequest, RawClientResponse};
+
+use crate::Expectation;
+
+/// The in-memory [`ClientBackend`] answering requests with registered [`Expectation`]s.
+#[derive(Default)]
+pub struct MockClient {
+    expectations: Mutex<Vec<Expectation>>,
+    unmatched: Mutex<Vec<UnmatchedRequest>>,
+}
+
+impl MockClient {
+    /// Create a new [`MockClient`] without any expectation.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Register the expectation.
+    ///
+    /// Expectations are tried in the order of registration, skipping the ones already met.
+    pub fn expect(&self, expectation: Expectation) -> &Self {
+        self.expectations.lock().unwrap().push(expectation);
+        self
+    }
+
+    /// Check that every expectation was met and no request was unmatched, reporting everything wrong otherwise.
+    pub fn verify(&self) -> Result<(), String> {
+        let mut report = Vec::new();
+        for expectation in self.expectations.lock().unwrap().iter() {
+            if expectation.calls != expectation.times {
+                report.push(format!(
+                    "expected {} to be requested {} time(s), but it was requested {} time(s)",
+                    expectation.describe(),
+                    expectation.times,
+                    expectation.calls
+                ));
+            }
+        }
+        for unmatched in self.unmatched.lock().unwrap().iter() {
+            report.push(unmatched.report.clone());
+        }
+        if report.is_empty() {
+            Ok(())
+        } else {
+            Err(report.join("\n"))
+        }
+    }
+
+    /// Panic with the report of [`MockClient::verify`] if anything was wrong.
+    pub fn assert_satisfied(&self) {
+        if let Err(report) = self.verify() {
+            panic!("mock client is not satisfied:\n{}", report);
+        }
+    }
+}
+
+impl ClientBackend for MockClient {
+    type Error = UnmatchedRequest;
+
+    async fn send(&self, request: RawClientRequest) -> Result<RawClientResponse, UnmatchedRequest> {
+        let mut expectations = self.expectations.lock().unwrap();
+        let mut closest: Option<(usize, Vec<String>)> = None;
+        for (index, expectation) in expectations.iter_mut().enumerate() {
+            if expectation.is_exhausted() {
+                continue;
+            }
+            let mismatches = expectation.mismatches(&request);
+            if mismatches.is_empty() {
+                expectation.calls += 1;
+                return Ok(expectation.response());
+            }
+            if closest
+                .as_ref()
+                .is_none_or(|(_, closest)| mismatches.len() < closest.len())
+            {
+                closest = Some((index, mismatches));
+            }
+        }
+
+        let mut report = format!("no expectation matched {} {}", request.method, request.url);
+        match closest {
+            Some((index, mismatches)) => {
+                report.push_str(&format!("\n  closest: {}", expectations[index].describe()));
+                for mismatch in mismatches {
+                    report.push_str(&format!("\n    - {}", mismatch));
+                }
+            }
+            None => report.push_str("\n  no expectation is left"),
+        }
+        let unmatched = UnmatchedRequest { request, report };
+        self.unmatched.lock().unwrap().push(unmatched.clone());
+        Err(unmatched)
+    }
+}
+
+/// The error for the request no [`Expectation`] matched.
+#[derive(Clone, Debug)]
+pub struct UnmatchedRequest {
+    /// The request sent.
+    pub request: RawClientRequest,
+    /// The readable report describing the differences from the closest expectation.
+    pub report: String,
+}
+
+impl std::error::Error for UnmatchedRequest {}
+
+impl core::fmt::Display for UnmatchedRequest {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.report)
+    }
+}
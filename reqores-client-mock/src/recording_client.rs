@@ -0,0 +1,78 @@
+use std::{io, path::PathBuf, sync::Mutex};
+
+use reqores::{ClientBackend, RawClientRequest, RawClientResponse};
+
+use crate::{Cassette, Interaction, RecordedRequest, RecordedResponse, Redactor};
+
+/// The [`ClientBackend`] recording every interaction of the inner backend into the cassette file.
+///
+/// The file is rewritten after each interaction, so nothing is lost when the test fails in the middle.
+pub struct RecordingClient<B> {
+    backend: B,
+    path: PathBuf,
+    redactor: Redactor,
+    cassette: Mutex<Cassette>,
+}
+
+impl<B> RecordingClient<B> {
+    /// Create a new [`RecordingClient`] starting an empty cassette at the path.
+    pub fn new(backend: B, path: impl Into<PathBuf>, redactor: Redactor) -> Self {
+        RecordingClient {
+            backend,
+            path: path.into(),
+            redactor,
+            cassette: Mutex::new(Cassette::default()),
+        }
+    }
+
+    /// The snapshot of the cassette recorded so far.
+    pub fn cassette(&self) -> Cassette {
+        self.cassette.lock().unwrap().clone()
+    }
+}
+
+impl<B: ClientBackend> ClientBackend for RecordingClient<B> {
+    type Error = RecordingError<B::Error>;
+
+    async fn send(&self, request: RawClientRequest) -> Result<RawClientResponse, Self::Error> {
+        let mut recorded_request = RecordedRequest::from(&request);
+        let response = self
+            .backend
+            .send(request)
+            .await
+            .map_err(RecordingError::Backend)?;
+        let mut recorded_response = RecordedResponse::from(&response);
+
+        self.redactor.redact_request(&mut recorded_request);
+        self.redactor.redact_response(&mut recorded_response);
+
+        let mut cassette = self.cassette.lock().unwrap();
+        cassette.interactions.push(Interaction {
+            request: recorded_request,
+            response: recorded_response,
+        });
+        cassette.save(&self.path).map_err(RecordingError::Io)?;
+
+        Ok(response)
+    }
+}
+
+/// The error type for [`RecordingClient`].
+#[derive(Debug)]
+pub enum RecordingError<E> {
+    /// The inner backend failed to send the request.
+    Backend(E),
+    /// The cassette could not be written.
+    Io(io::Error),
+}
+
+impl<E: core::fmt::Debug + core::fmt::Display> std::error::Error for RecordingError<E> {}
+
+impl<E: core::fmt::Display> core::fmt::Display for RecordingError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RecordingError::Backend(e) => write!(f, "Backend Error: {}", e),
+            RecordingError::Io(e) => write!(f, "Cassette Error: {}", e),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use std::{io, path::Path, sync::Mutex};
+
+use reqores::{ClientBackend, RawClientRequest, RawClientResponse};
+
+use crate::{Cassette, RecordedRequest, Redactor};
+
+/// The [`ClientBackend`] serving responses from the cassette without network.
+///
+/// Requests are redacted and matched by method, URL and body in the order of the cassette,
+/// and each interaction is served only once.
+pub struct ReplayClient {
+    cassette: Cassette,
+    redactor: Redactor,
+    played: Mutex<Vec<bool>>,
+}
+
+impl ReplayClient {
+    /// Create a new [`ReplayClient`] with the cassette.
+    pub fn new(cassette: Cassette, redactor: Redactor) -> Self {
+        let played = vec![false; cassette.interactions.len()];
+        ReplayClient {
+            cassette,
+            redactor,
+            played: Mutex::new(played),
+        }
+    }
+
+    /// Create a new [`ReplayClient`] with the cassette file.
+    pub fn open(path: impl AsRef<Path>, redactor: Redactor) -> io::Result<Self> {
+        Ok(ReplayClient::new(Cassette::load(path)?, redactor))
+    }
+
+    /// Check that every interaction in the cassette was played.
+    pub fn verify(&self) -> Result<(), String> {
+        let unplayed: Vec<_> = self
+            .played
+            .lock()
+            .unwrap()
+            .iter()
+            .zip(&self.cassette.interactions)
+            .filter(|(played, _)| !**played)
+            .map(|(_, interaction)| {
+                format!(
+                    "  {} {}",
+                    interaction.request.method, interaction.request.url
+                )
+            })
+            .collect();
+        if unplayed.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "interactions were not played:\n{}",
+                unplayed.join("\n")
+            ))
+        }
+    }
+}
+
+impl ClientBackend for ReplayClient {
+    type Error = ReplayError;
+
+    async fn send(&self, request: RawClientRequest) -> Result<RawClientResponse, ReplayError> {
+        let mut recorded_request = RecordedRequest::from(&request);
+        self.redactor.redact_request(&mut recorded_request);
+
+        let mut played = self.played.lock().unwrap();
+        let interactions = &self.cassette.interactions;
+        let index = (0..interactions.len())
+            .find(|&index| !played[index] && interactions[index].request.matches(&recorded_request))
+            .ok_or_else(|| ReplayError {
+                message: format!(
+                    "no recorded interaction matches {} {} with body {:?}",
+                    recorded_request.method, recorded_request.url, recorded_request.body
+                ),
+            })?;
+        played[index] = true;
+
+        RawClientResponse::try_from(interactions[index].response.clone())
+            .map_err(|message| ReplayError { message })
+    }
+}
+
+/// The error type for [`ReplayClient`].
+#[derive(Clone, Debug)]
+pub struct ReplayError {
+    /// The description of what went wrong.
+    pub message: String,
+}
+
+impl std::error::Error for ReplayError {}
+
+impl core::fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Replay Error: {}", self.message)
+    }
+}
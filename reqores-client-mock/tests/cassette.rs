@@ -0,0 +1,127 @@
+use std::path::PathBuf;
+
+use futures::executor::block_on;
+use reqores::{ClientBackend, HttpMethod, HttpStatusCode, RawClientRequest};
+use reqores_client_mock::{
+    Cassette, Expectation, MockClient, RecordedBody, RecordingClient, Redactor, ReplayClient,
+};
+
+const TOKEN: &str = "123456:SECRET";
+
+fn cassette_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "reqores-cassette-{}-{}.json",
+        name,
+        std::process::id()
+    ))
+}
+
+fn redactor() -> Redactor {
+    Redactor::new()
+        .header("Authorization")
+        .secret(TOKEN)
+        .with_response_hook(|response| {
+            response
+                .headers
+                .retain(|(name, _)| !name.eq_ignore_ascii_case("Date"))
+        })
+}
+
+fn get_me() -> RawClientRequest {
+    RawClientRequest {
+        method: HttpMethod::Get,
+        url: format!("https://api.telegram.org/bot{}/getMe", TOKEN),
+        headers: vec![("Authorization".to_string(), "Bearer abc".to_string())],
+        body: None,
+    }
+}
+
+fn upload() -> RawClientRequest {
+    RawClientRequest {
+        method: HttpMethod::Post,
+        url: "https://example.com/upload".to_string(),
+        headers: vec![],
+        body: Some(vec![0xff, 0x00]),
+    }
+}
+
+#[test]
+fn test_record_and_replay() {
+    let path = cassette_path("record-and-replay");
+    let mock = MockClient::new();
+    mock.expect(
+        Expectation::new(HttpMethod::Get, get_me().url)
+            .with_response_header("Date", "Sun, 06 Nov 1994 08:49:37 GMT")
+            .with_response_body(format!("{{\"token\":\"{}\"}}", TOKEN)),
+    )
+    .expect(
+        Expectation::new(HttpMethod::Post, "https://example.com/upload")
+            .with_response_status(HttpStatusCode::Created)
+            .with_response_body(vec![0xfe]),
+    );
+
+    let recording = RecordingClient::new(&mock, &path, redactor());
+    block_on(recording.send(get_me())).unwrap();
+    block_on(recording.send(upload())).unwrap();
+    mock.assert_satisfied();
+
+    let cassette = Cassette::load(&path).unwrap();
+    assert_eq!(cassette, recording.cassette());
+    let first = &cassette.interactions[0];
+    assert_eq!(
+        first.request.url,
+        "https://api.telegram.org/bot[REDACTED]/getMe"
+    );
+    assert_eq!(
+        first.request.headers,
+        vec![("Authorization".to_string(), "[REDACTED]".to_string())]
+    );
+    assert!(first.response.headers.is_empty());
+    assert_eq!(
+        first.response.body,
+        RecordedBody::Text("{\"token\":\"[REDACTED]\"}".to_string())
+    );
+    assert_eq!(
+        cassette.interactions[1].request.body,
+        Some(RecordedBody::Bytes(vec![0xff, 0x00]))
+    );
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert!(!content.contains(TOKEN));
+    assert!(content.contains("\n  \"interactions\": [\n"));
+
+    let replay = ReplayClient::open(&path, redactor()).unwrap();
+    assert!(replay.verify().is_err());
+    let uploaded = block_on(replay.send(upload())).unwrap();
+    assert_eq!(uploaded.status, HttpStatusCode::Created);
+    assert_eq!(uploaded.body, vec![0xfe]);
+    let me = block_on(replay.send(get_me())).unwrap();
+    assert_eq!(me.status, HttpStatusCode::Ok);
+    replay.verify().unwrap();
+
+    let error = block_on(replay.send(get_me())).unwrap_err();
+    assert_eq!(
+        error.message,
+        "no recorded interaction matches GET https://api.telegram.org/bot[REDACTED]/getMe with body None"
+    );
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_replay_unmatched_body() {
+    let path = cassette_path("unmatched-body");
+    let mock = MockClient::new();
+    mock.expect(Expectation::new(
+        HttpMethod::Post,
+        "https://example.com/upload",
+    ));
+    let recording = RecordingClient::new(&mock, &path, Redactor::new());
+    block_on(recording.send(upload())).unwrap();
+
+    let replay = ReplayClient::new(recording.cassette(), Redactor::new());
+    let mut other = upload();
+    other.body = Some(b"other".to_vec());
+    assert!(block_on(replay.send(other)).is_err());
+
+    std::fs::remove_file(path).unwrap();
+}
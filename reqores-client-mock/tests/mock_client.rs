@@ -0,0 +1,191 @@
+use futures::executor::block_on;
+use reqores::{
+    ClientBackend, ClientCallError, ClientRequest, HttpMethod, HttpStatusCode, RawClientRequest,
+};
+use reqores_client_mock::{Expectation, MockClient};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Message {
+    text: String,
+}
+
+struct SendMessage {
+    text: String,
+}
+
+impl ClientRequest for SendMessage {
+    type Response = Message;
+
+    fn url(&self) -> String {
+        "https://example.com/sendMessage".to_string()
+    }
+
+    fn body(&self) -> Option<String> {
+        Some(format!("{{ \"text\": {:?} }}", self.text))
+    }
+
+    fn method(&self) -> HttpMethod {
+        HttpMethod::Post
+    }
+}
+
+#[test]
+fn test_call_with_expectation() {
+    let client = MockClient::new();
+    client.expect(
+        Expectation::new(HttpMethod::Post, "https://example.com/sendMessage")
+            .with_header("content-type", "application/json; charset=UTF-8")
+            .with_json_body(&Message {
+                text: "hi".to_string(),
+            })
+            .with_response_json(&Message {
+                text: "hi".to_string(),
+            }),
+    );
+
+    let response = block_on(client.call(SendMessage {
+        text: "hi".to_string(),
+    }))
+    .unwrap();
+    assert_eq!(
+        response,
+        Message {
+            text: "hi".to_string()
+        }
+    );
+    client.assert_satisfied();
+}
+
+#[test]
+fn test_canned_response() {
+    let client = MockClient::new();
+    client.expect(
+        Expectation::new(HttpMethod::Get, "https://example.com/")
+            .with_response_status(HttpStatusCode::Notfound)
+            .with_response_header("X-Request-Id", "1")
+            .with_response_body("missing"),
+    );
+
+    let response = block_on(client.send(RawClientRequest {
+        method: HttpMethod::Get,
+        url: "https://example.com/".to_string(),
+        headers: vec![],
+        body: None,
+    }))
+    .unwrap();
+    assert_eq!(response.status, HttpStatusCode::Notfound);
+    assert_eq!(
+        response.headers,
+        vec![("X-Request-Id".to_string(), "1".to_string())]
+    );
+    assert_eq!(response.body, b"missing");
+}
+
+#[test]
+fn test_times_and_order() {
+    let client = MockClient::new();
+    client
+        .expect(
+            Expectation::new(HttpMethod::Get, "https://example.com/")
+                .times(2)
+                .with_response_body("first"),
+        )
+        .expect(
+            Expectation::new(HttpMethod::Get, "https://example.com/").with_response_body("second"),
+        );
+
+    let request = RawClientRequest {
+        method: HttpMethod::Get,
+        url: "https://example.com/".to_string(),
+        headers: vec![],
+        body: None,
+    };
+    let bodies: Vec<_> = (0..3)
+        .map(|_| block_on(client.send(request.clone())).unwrap().body)
+        .collect();
+    assert_eq!(
+        bodies,
+        vec![b"first".to_vec(), b"first".to_vec(), b"second".to_vec()]
+    );
+    client.assert_satisfied();
+
+    let error = block_on(client.send(request)).unwrap_err();
+    assert!(error.report.contains("no expectation is left"));
+    assert!(client.verify().is_err());
+}
+
+#[test]
+fn test_unmatched_report() {
+    let client = MockClient::new();
+    client.expect(
+        Expectation::new(HttpMethod::Post, "https://example.com/sendMessage")
+            .with_header("Authorization", "Bearer token")
+            .with_json_body(&Message {
+                text: "hello".to_string(),
+            }),
+    );
+    client.expect(Expectation::new(
+        HttpMethod::Get,
+        "https://example.com/other",
+    ));
+
+    let error = block_on(client.call(SendMessage {
+        text: "hi".to_string(),
+    }))
+    .unwrap_err();
+    let report = match error {
+        ClientCallError::Backend(unmatched) => unmatched.report,
+        ClientCallError::Deserialize(e) => panic!("unexpected deserialize error: {}", e),
+    };
+    assert_eq!(
+        report,
+        [
+            "no expectation matched POST https://example.com/sendMessage",
+            "  closest: POST https://example.com/sendMessage",
+            "    - header \"Authorization\": expected \"Bearer token\", got None",
+            "    - json body: expected {\"text\":\"hello\"}, got {\"text\":\"hi\"}",
+        ]
+        .join("\n")
+    );
+
+    let verification = client.verify().unwrap_err();
+    assert!(verification.contains(
+        "expected POST https://example.com/sendMessage to be requested 1 time(s), but it was requested 0 time(s)"
+    ));
+    assert!(verification.contains(&report));
+}
+
+#[test]
+fn test_method_mismatch_report() {
+    let client = MockClient::new();
+    client.expect(Expectation::new(HttpMethod::Get, "https://example.com/"));
+
+    let error = block_on(client.send(RawClientRequest {
+        method: HttpMethod::Delete,
+        url: "https://example.com/".to_string(),
+        headers: vec![],
+        body: None,
+    }))
+    .unwrap_err();
+    assert!(error.report.contains("method: expected GET, got DELETE"));
+}
+
+#[test]
+fn test_predicate() {
+    let client = MockClient::new();
+    client.expect(
+        Expectation::new(HttpMethod::Get, "https://example.com/")
+            .matching("has no body", |request| request.body.is_none()),
+    );
+    let error = block_on(client.send(RawClientRequest {
+        method: HttpMethod::Get,
+        url: "https://example.com/".to_string(),
+        headers: vec![],
+        body: Some(b"unexpected".to_vec()),
+    }))
+    .unwrap_err();
+    assert!(error
+        .report
+        .contains("predicate \"has no body\" is not satisfied"));
+}
@@ -0,0 +1,89 @@
+use reqores::{
+    ClientBackend, ClientRequest, HttpMethod, HttpStatusCodeParseError, RawClientRequest,
+    RawClientResponse,
+};
+use reqwest::{Client, Method};
+
+use crate::client_response::ReqwestClientResponse;
+
+#[derive(Default)]
+pub struct ReqwestClient(Client);
+
+impl ReqwestClient {
+    pub fn new() -> Self {
+        ReqwestClient(Client::new())
+    }
+    pub fn with_client(client: Client) -> Self {
+        ReqwestClient(client)
+    }
+}
+
+impl ReqwestClient {
+    pub async fn call<Req: ClientRequest>(
+        &self,
+        client_request: Req,
+    ) -> Result<Req::Response, ReqwestClientError> {
+        let client_response = self.send(RawClientRequest::new(&client_request)).await?;
+
+        client_request
+            .deserialize(&client_response)
+            .map_err(ReqwestClientError::Deserialize)
+    }
+}
+
+impl ClientBackend for ReqwestClient {
+    type Error = ReqwestClientError;
+
+    async fn send(
+        &self,
+        raw_request: RawClientRequest,
+    ) -> Result<RawClientResponse, ReqwestClientError> {
+        let method = match raw_request.method {
+            HttpMethod::Get => Method::GET,
+            HttpMethod::Put => Method::PUT,
+            HttpMethod::Post => Method::POST,
+            HttpMethod::Delete => Method::DELETE,
+            HttpMethod::Patch => Method::PATCH,
+            HttpMethod::Head => Method::HEAD,
+            HttpMethod::Options => Method::OPTIONS,
+            HttpMethod::Connect => Method::CONNECT,
+            HttpMethod::Trace => Method::TRACE,
+        };
+        let mut request = self.0.request(method, &raw_request.url);
+        for (k, v) in raw_request.headers {
+            request = request.header(k, v);
+        }
+
+        if let Some(body) = raw_request.body {
+            request = request.body(body);
+        }
+
+        let response = request.send().await.map_err(ReqwestClientError::Reqwest)?;
+        let client_response = ReqwestClientResponse::new(response).await?;
+
+        Ok(client_response.into_raw())
+    }
+}
+
+/// The error type for [`ReqwestClient`].
+#[derive(Debug)]
+pub enum ReqwestClientError {
+    /// The request failed to be sent or the response failed to be received.
+    Reqwest(reqwest::Error),
+    /// The response has the status [`reqores::HttpStatusCode`] does not know.
+    Status(HttpStatusCodeParseError),
+    /// The response failed to be deserialized.
+    Deserialize(String),
+}
+
+impl std::error::Error for ReqwestClientError {}
+
+impl core::fmt::Display for ReqwestClientError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ReqwestClientError::Reqwest(e) => write!(f, "Reqwest Error: {}", e),
+            ReqwestClientError::Status(e) => write!(f, "Status Error: {}", e),
+            ReqwestClientError::Deserialize(e) => write!(f, "Deserialize Error: {}", e),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use reqores::{ClientResponse, HttpStatusCode, RawClientResponse};
+use reqwest::{header::HeaderMap, Response};
+
+use crate::ReqwestClientError;
+
+pub struct ReqwestClientResponse {
+    body: Vec<u8>,
+    headers: HeaderMap,
+    status: HttpStatusCode,
+}
+
+impl ReqwestClientResponse {
+    pub async fn new(response: Response) -> Result<Self, ReqwestClientError> {
+        let status = HttpStatusCode::try_from(response.status().as_u16())
+            .map_err(ReqwestClientError::Status)?;
+        let headers = response.headers().clone();
+        let body = response
+            .bytes()
+            .await
+            .map_err(ReqwestClientError::Reqwest)?
+            .to_vec();
+        Ok(Self {
+            body,
+            headers,
+            status,
+        })
+    }
+
+    pub fn into_raw(self) -> RawClientResponse {
+        let headers = self
+            .headers
+            .iter()
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        RawClientResponse {
+            status: self.status,
+            headers,
+            body: self.body,
+        }
+    }
+}
+
+impl ClientResponse for ReqwestClientResponse {
+    fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    fn status(&self) -> HttpStatusCode {
+        self.status.clone()
+    }
+
+    fn header(&self, key: &str) -> Option<String> {
+        self.headers
+            .get(key)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string())
+    }
+}
@@ -0,0 +1,4 @@
+pub use api_client::{ReqwestClient, ReqwestClientError};
+
+mod api_client;
+mod client_response;
@@ -0,0 +1,30 @@
+use reqores_client_reqwest::ReqwestClient;
+use reqores_test_support::conformance::Conformance;
+
+#[tokio::test]
+async fn reqwest_client_passes_conformance() {
+    let client = ReqwestClient::new();
+
+    // reqwest follows up to 10 redirects by default.
+    let conformance = Conformance::new().following_redirects();
+
+    conformance
+        .run(&client)
+        .await
+        .unwrap_or_else(|report| panic!("{}", report));
+}
+
+#[tokio::test]
+async fn reqwest_client_without_redirects_passes_conformance() {
+    let client = ReqwestClient::with_client(
+        reqwest::Client::builder()
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .unwrap(),
+    );
+
+    Conformance::new()
+        .run(&client)
+        .await
+        .unwrap_or_else(|report| panic!("{}", report));
+}
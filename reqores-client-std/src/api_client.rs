@@ -0,0 +1,167 @@
+use std::{
+    io::{self, Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+    time::Duration,
+};
+
+use reqores::{
+    wire::{WireError, WireLimits, WireResponse},
+    BlockingClientBackend, RawClientRequest, RawClientResponse,
+};
+
+use crate::url::HttpUrl;
+
+/// The client sending each request on a new connection, closed after the response.
+///
+/// [`ClientRequest`](reqores::ClientRequest)s are called with [`BlockingClientBackend::call`].
+pub struct StdClient {
+    timeout: Option<Duration>,
+    limits: WireLimits,
+}
+
+impl Default for StdClient {
+    fn default() -> Self {
+        StdClient::new()
+    }
+}
+
+impl StdClient {
+    pub fn new() -> Self {
+        StdClient {
+            timeout: Some(Duration::from_secs(30)),
+            limits: WireLimits::default(),
+        }
+    }
+
+    /// Set the timeout for connecting, and for each read and write, where [`None`] waits forever.
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the limits applied to responses.
+    pub fn with_limits(mut self, limits: WireLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+}
+
+impl StdClient {
+    fn connect(&self, url: &HttpUrl) -> io::Result<TcpStream> {
+        let mut last_error = None;
+        for addr in (url.host, url.port).to_socket_addrs()? {
+            let stream = match self.timeout {
+                Some(timeout) => TcpStream::connect_timeout(&addr, timeout),
+                None => TcpStream::connect(addr),
+            };
+            match stream {
+                Ok(stream) => return Ok(stream),
+                Err(e) => last_error = Some(e),
+            }
+        }
+        Err(last_error
+            .unwrap_or_else(|| io::Error::new(io::ErrorKind::NotFound, "the host has no address")))
+    }
+}
+
+impl BlockingClientBackend for StdClient {
+    type Error = StdClientError;
+
+    fn send(&self, raw_request: RawClientRequest) -> Result<RawClientResponse, StdClientError> {
+        let url = HttpUrl::parse(&raw_request.url)?;
+
+        let mut head = format!("{} {} HTTP/1.1\r\n", raw_request.method, url.target);
+        if raw_request.header("Host").is_none() {
+            head.push_str(&format!("Host: {}\r\n", url.authority));
+        }
+        for (name, value) in &raw_request.headers {
+            let forbidden = |byte: u8| byte == b'\r' || byte == b'\n' || byte == 0;
+            if name.is_empty()
+                || name
+                    .bytes()
+                    .any(|byte| forbidden(byte) || byte == b':' || byte == b' ')
+                || value.bytes().any(forbidden)
+            {
+                return Err(StdClientError::Wire(WireError::InvalidHeader));
+            }
+            // The framing is decided by the client.
+            if ["Connection", "Content-Length", "Transfer-Encoding"]
+                .iter()
+                .any(|framing| framing.eq_ignore_ascii_case(name))
+            {
+                continue;
+            }
+            head.push_str(&format!("{}: {}\r\n", name, value));
+        }
+        head.push_str("Connection: close\r\n");
+        if let Some(body) = &raw_request.body {
+            head.push_str(&format!("Content-Length: {}\r\n", body.len()));
+        }
+        head.push_str("\r\n");
+
+        let mut stream = self.connect(&url)?;
+        stream.set_read_timeout(self.timeout)?;
+        stream.set_write_timeout(self.timeout)?;
+        stream.write_all(head.as_bytes())?;
+        if let Some(body) = &raw_request.body {
+            stream.write_all(body)?;
+        }
+        stream.flush()?;
+
+        // The connection is closed after the response, so everything until the end belongs to it.
+        let cap = self
+            .limits
+            .max_line_size
+            .saturating_mul(self.limits.max_headers.saturating_add(1))
+            .saturating_mul(2)
+            .saturating_add(self.limits.max_body_size);
+        let mut input = Vec::new();
+        stream
+            .take((cap as u64).saturating_add(1))
+            .read_to_end(&mut input)?;
+        if input.len() > cap {
+            return Err(StdClientError::Wire(WireError::BodyTooLarge));
+        }
+
+        let (response, _) = WireResponse::parse(&input, &self.limits, raw_request.method)
+            .map_err(StdClientError::Wire)?;
+        Ok(response.into())
+    }
+}
+
+/// The error type for [`StdClient`].
+#[derive(Debug)]
+pub enum StdClientError {
+    /// The URL is not the absolute `http://` URL.
+    InvalidUrl(String),
+    /// The URL has the scheme other than `http`, like `https` which needs TLS.
+    UnsupportedScheme(String),
+    /// Connecting, writing or reading failed, including timeouts.
+    Io(io::Error),
+    /// The request could not be written, or the response could not be parsed.
+    /// [`WireError::Incomplete`] means the connection was closed before the response ended.
+    Wire(WireError),
+}
+
+impl From<io::Error> for StdClientError {
+    fn from(error: io::Error) -> Self {
+        StdClientError::Io(error)
+    }
+}
+
+impl std::error::Error for StdClientError {}
+
+impl core::fmt::Display for StdClientError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            StdClientError::InvalidUrl(url) => write!(f, "Invalid URL: {}", url),
+            StdClientError::UnsupportedScheme(scheme) => write!(
+                f,
+                "Unsupported Scheme: {} (only plain http is supported)",
+                scheme
+            ),
+            StdClientError::Io(e) => write!(f, "IO Error: {}", e),
+            StdClientError::Wire(e) => write!(f, "Wire Error: {}", e),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+//! reqores blocking client implementation with only [`std::net`], for plain-HTTP local services like sidecars and test servers.
+//!
+//! `https://` URLs are rejected with [`StdClientError::UnsupportedScheme`], since there is no TLS.
+
+pub use api_client::{StdClient, StdClientError};
+
+mod api_client;
+mod url;
@@ -0,0 +1,55 @@
+use crate::StdClientError;
+
+/// The parts of the plain-HTTP URL needed to send a request.
+pub(crate) struct HttpUrl<'a> {
+    /// The host and the optional port, sent as "Host".
+    pub(crate) authority: &'a str,
+    pub(crate) host: &'a str,
+    pub(crate) port: u16,
+    /// The path and the query, like "/path?query".
+    pub(crate) target: String,
+}
+
+impl<'a> HttpUrl<'a> {
+    pub(crate) fn parse(url: &'a str) -> Result<Self, StdClientError> {
+        let invalid = || StdClientError::InvalidUrl(url.to_string());
+        let (scheme, rest) = url.split_once("://").ok_or_else(invalid)?;
+        if !scheme.eq_ignore_ascii_case("http") {
+            return Err(StdClientError::UnsupportedScheme(scheme.to_string()));
+        }
+
+        let rest = rest.split('#').next().unwrap_or_default();
+        let end = rest.find(['/', '?']).unwrap_or(rest.len());
+        let (authority, target) = rest.split_at(end);
+        if authority.is_empty() || authority.contains('@') {
+            return Err(invalid());
+        }
+        let target = match target.strip_prefix('?') {
+            Some(query) => format!("/?{}", query),
+            None if target.is_empty() => "/".to_string(),
+            None => target.to_string(),
+        };
+
+        // The port is after the last colon, unless the colon is inside the IPv6 literal like "[::1]".
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) if !port.contains(']') => {
+                (host, port.parse().map_err(|_| invalid())?)
+            }
+            _ => (authority, 80),
+        };
+        let host = host
+            .strip_prefix('[')
+            .and_then(|host| host.strip_suffix(']'))
+            .unwrap_or(host);
+        if host.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(HttpUrl {
+            authority,
+            host,
+            port,
+            target,
+        })
+    }
+}
@@ -0,0 +1,12 @@
+use reqores_client_std::StdClient;
+use reqores_test_support::conformance::Conformance;
+
+#[test]
+fn std_client_passes_conformance() {
+    let client = StdClient::new();
+
+    // StdClient never follows redirects.
+    Conformance::new()
+        .run_blocking(&client)
+        .unwrap_or_else(|report| panic!("{}", report));
+}
@@ -0,0 +1,129 @@
+use std::{io::Read, net::TcpListener, thread, time::Duration};
+
+use reqores::{
+    wire::WireLimits, BlockingClientBackend, HttpMethod, HttpStatusCode, RawClientRequest,
+    ServerRequest, ServerResponseBuilder,
+};
+use reqores_client_std::{StdClient, StdClientError};
+use reqores_test_support::stub::StubServerBuilder;
+
+fn request(method: HttpMethod, url: impl Into<String>) -> RawClientRequest {
+    RawClientRequest {
+        method,
+        url: url.into(),
+        headers: Vec::new(),
+        body: None,
+    }
+}
+
+#[test]
+fn https_is_rejected() {
+    let result = StdClient::new().send(request(HttpMethod::Get, "https://example.com/"));
+
+    match result {
+        Err(StdClientError::UnsupportedScheme(scheme)) => assert_eq!(scheme, "https"),
+        other => panic!("unexpected result: {:?}", other.map(|r| r.status)),
+    }
+}
+
+#[test]
+fn invalid_urls_are_rejected() {
+    for url in [
+        "example.com/",
+        "http://",
+        "http:///path",
+        "http://user@example.com/",
+        "http://example.com:port/",
+    ] {
+        let result = StdClient::new().send(request(HttpMethod::Get, url));
+        assert!(
+            matches!(result, Err(StdClientError::InvalidUrl(_))),
+            "{} was accepted",
+            url
+        );
+    }
+}
+
+#[test]
+fn request_target_and_host_are_sent() {
+    let server = StubServerBuilder::new()
+        .with_route(
+            HttpMethod::Post,
+            "/",
+            ServerResponseBuilder::new()
+                .with_status(HttpStatusCode::Created)
+                .body_str("created"),
+        )
+        .start()
+        .unwrap();
+    let url = format!("http://{}?name=value#fragment", server.addr());
+    let mut raw_request = request(HttpMethod::Post, url);
+    raw_request.body = Some(b"payload".to_vec());
+
+    let response = StdClient::new().send(raw_request).unwrap();
+
+    assert_eq!(response.status, HttpStatusCode::Created);
+    assert_eq!(response.body, b"created");
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(
+        requests[0].url(),
+        format!("http://{}/?name=value", server.addr())
+    );
+    assert_eq!(requests[0].body(), b"payload");
+}
+
+#[test]
+fn unbounded_limits_do_not_overflow() {
+    let server = StubServerBuilder::new()
+        .with_route(
+            HttpMethod::Get,
+            "/",
+            ServerResponseBuilder::new().body_str("ok"),
+        )
+        .start()
+        .unwrap();
+    let client = StdClient::new().with_limits(WireLimits {
+        max_headers: usize::MAX,
+        max_line_size: usize::MAX,
+        max_body_size: usize::MAX,
+    });
+
+    let response = client
+        .send(request(HttpMethod::Get, server.url("/")))
+        .unwrap();
+
+    assert_eq!(response.body, b"ok");
+}
+
+#[test]
+fn header_injection_is_rejected() {
+    let mut raw_request = request(HttpMethod::Get, "http://127.0.0.1:1/");
+    raw_request
+        .headers
+        .push(("X-Test".to_string(), "1\r\nX-Injected: 2".to_string()));
+
+    let result = StdClient::new().send(raw_request);
+
+    assert!(matches!(result, Err(StdClientError::Wire(_))));
+}
+
+#[test]
+fn slow_server_times_out() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = thread::spawn(move || {
+        // Read the request but never answer.
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut buffer = [0; 1024];
+        let _ = stream.read(&mut buffer);
+        thread::sleep(Duration::from_secs(1));
+    });
+
+    let result = StdClient::new()
+        .with_timeout(Some(Duration::from_millis(100)))
+        .send(request(HttpMethod::Get, format!("http://{}/", addr)));
+
+    assert!(matches!(result, Err(StdClientError::Io(_))));
+    server.join().unwrap();
+}
@@ -1,8 +1,9 @@
-use reqores::{ClientRequest, HttpMethod};
+use reqores::{ClientBackend, ClientRequest, HttpMethod, RawClientRequest, RawClientResponse};
 use surf::Client;
 
 use crate::client_response::SurfClientResponse;
 
+#[derive(Default)]
 pub struct SurfClient(Client);
 
 impl SurfClient {
@@ -19,26 +20,46 @@ impl SurfClient {
         &self,
         client_request: Req,
     ) -> surf::Result<Req::Response> {
-        let mut request = match client_request.method() {
-            HttpMethod::Get => self.0.get(&client_request.url()),
-            HttpMethod::Put => self.0.put(&client_request.url()),
-            HttpMethod::Post => self.0.post(&client_request.url()),
-            HttpMethod::Delete => self.0.delete(&client_request.url()),
-            HttpMethod::Patch => self.0.patch(&client_request.url()),
-        };
-        for (k, v) in client_request.headers() {
-            request = request.header(&*k, v);
+        let client_response = self.send(RawClientRequest::new(&client_request)).await?;
+
+        client_request
+            .deserialize(&client_response)
+            .map_err(|s| surf::Error::from_str(500, s))
+    }
+}
+
+impl ClientBackend for SurfClient {
+    type Error = surf::Error;
+
+    async fn send(&self, raw_request: RawClientRequest) -> surf::Result<RawClientResponse> {
+        let mut request = match raw_request.method {
+            HttpMethod::Get => self.0.get(&raw_request.url),
+            HttpMethod::Put => self.0.put(&raw_request.url),
+            HttpMethod::Post => self.0.post(&raw_request.url),
+            HttpMethod::Delete => self.0.delete(&raw_request.url),
+            HttpMethod::Patch => self.0.patch(&raw_request.url),
+            HttpMethod::Head => self.0.head(&raw_request.url),
+            HttpMethod::Options => self.0.options(&raw_request.url),
+            HttpMethod::Connect => self.0.connect(&raw_request.url),
+            HttpMethod::Trace => self.0.trace(&raw_request.url),
+        }
+        .build();
+        // http-client sends only the first value of each header, so repeated headers are combined.
+        for (k, v) in raw_request.headers {
+            let value = match request.header(&*k) {
+                Some(existing) => format!("{}, {}", existing.as_str(), v),
+                None => v,
+            };
+            request.insert_header(&*k, value);
         }
 
-        if let Some(body) = client_request.body() {
-            request = request.body(body);
+        if let Some(body) = raw_request.body {
+            request.set_body(body);
         }
 
-        let response = request.send().await?;
+        let response = self.0.send(request).await?;
         let client_response = SurfClientResponse::new(response).await?;
 
-        client_request
-            .deserialize(&client_response)
-            .map_err(|s| surf::Error::from_str(500, s))
+        Ok(client_response.into_raw())
     }
 }
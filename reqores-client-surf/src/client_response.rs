@@ -1,18 +1,40 @@
-use reqores::{ClientResponse, HttpStatusCode};
-use surf::{Response, StatusCode as SurfStatus};
+use reqores::{ClientResponse, HttpStatusCode, RawClientResponse};
+use surf::Response;
 
 pub struct SurfClientResponse {
     body: Vec<u8>,
     response: Response,
+    status: HttpStatusCode,
 }
 
 impl SurfClientResponse {
     pub async fn new(mut response: Response) -> surf::Result<Self> {
+        let body = response.body_bytes().await?;
+        let status = HttpStatusCode::try_from(u16::from(response.status()))
+            .map_err(|e| surf::Error::from_str(500, e.to_string()))?;
         Ok(Self {
-            body: response.body_bytes().await?,
+            body,
             response,
+            status,
         })
     }
+
+    pub fn into_raw(self) -> RawClientResponse {
+        let headers = self
+            .response
+            .iter()
+            .flat_map(|(name, values)| {
+                values
+                    .iter()
+                    .map(move |value| (name.to_string(), value.to_string()))
+            })
+            .collect();
+        RawClientResponse {
+            status: self.status,
+            headers,
+            body: self.body,
+        }
+    }
 }
 
 impl ClientResponse for SurfClientResponse {
@@ -21,69 +43,7 @@ impl ClientResponse for SurfClientResponse {
     }
 
     fn status(&self) -> HttpStatusCode {
-        match self.response.status() {
-            SurfStatus::Continue => HttpStatusCode::Continue,
-            SurfStatus::SwitchingProtocols => HttpStatusCode::SwitchingProtocols,
-            SurfStatus::EarlyHints => HttpStatusCode::EarlyHints,
-            SurfStatus::Ok => HttpStatusCode::Ok,
-            SurfStatus::Created => HttpStatusCode::Created,
-            SurfStatus::Accepted => HttpStatusCode::Accepted,
-            SurfStatus::NonAuthoritativeInformation => HttpStatusCode::NonAuthoritativeInformation,
-            SurfStatus::NoContent => HttpStatusCode::NoContent,
-            SurfStatus::ResetContent => HttpStatusCode::ResetContent,
-            SurfStatus::PartialContent => HttpStatusCode::PartialContent,
-            SurfStatus::MultiStatus => HttpStatusCode::MultiStatus,
-            SurfStatus::ImUsed => HttpStatusCode::ImUsed,
-            SurfStatus::MultipleChoice => HttpStatusCode::MultipleChoices,
-            SurfStatus::MovedPermanently => HttpStatusCode::MovedPermanently,
-            SurfStatus::Found => HttpStatusCode::Found,
-            SurfStatus::SeeOther => HttpStatusCode::SeeOther,
-            SurfStatus::NotModified => HttpStatusCode::NotModified,
-            SurfStatus::TemporaryRedirect => HttpStatusCode::TemporaryRedirect,
-            SurfStatus::PermanentRedirect => HttpStatusCode::PermanentRedirect,
-            SurfStatus::BadRequest => HttpStatusCode::BadRequest,
-            SurfStatus::Unauthorized => HttpStatusCode::Unauthorized,
-            SurfStatus::PaymentRequired => HttpStatusCode::PaymentRequired,
-            SurfStatus::Forbidden => HttpStatusCode::Forbidden,
-            SurfStatus::NotFound => HttpStatusCode::Notfound,
-            SurfStatus::MethodNotAllowed => HttpStatusCode::MethodNotAllowed,
-            SurfStatus::NotAcceptable => HttpStatusCode::NotAcceptable,
-            SurfStatus::ProxyAuthenticationRequired => HttpStatusCode::ProxyAuthenticationRequired,
-            SurfStatus::RequestTimeout => HttpStatusCode::RequestTimeout,
-            SurfStatus::Conflict => HttpStatusCode::Conflict,
-            SurfStatus::Gone => HttpStatusCode::Gone,
-            SurfStatus::LengthRequired => HttpStatusCode::LengthRequired,
-            SurfStatus::PreconditionFailed => HttpStatusCode::PreconditionFailed,
-            SurfStatus::PayloadTooLarge => HttpStatusCode::PayloadTooLarge,
-            SurfStatus::UriTooLong => HttpStatusCode::UriTooLong,
-            SurfStatus::UnsupportedMediaType => HttpStatusCode::UnsupportedMediaType,
-            SurfStatus::RequestedRangeNotSatisfiable => HttpStatusCode::RangeNotSatisfiable,
-            SurfStatus::ExpectationFailed => HttpStatusCode::ExpectationFailed,
-            SurfStatus::ImATeapot => HttpStatusCode::ImATeapot,
-            SurfStatus::MisdirectedRequest => HttpStatusCode::MisdirectedRequest,
-            SurfStatus::UnprocessableEntity => HttpStatusCode::UnprocessableEntity,
-            SurfStatus::Locked => HttpStatusCode::Locked,
-            SurfStatus::FailedDependency => HttpStatusCode::FailedDependency,
-            SurfStatus::TooEarly => HttpStatusCode::TooEarly,
-            SurfStatus::UpgradeRequired => HttpStatusCode::UpgradeRequired,
-            SurfStatus::PreconditionRequired => HttpStatusCode::PreconditionRequired,
-            SurfStatus::TooManyRequests => HttpStatusCode::TooManyRequests,
-            SurfStatus::RequestHeaderFieldsTooLarge => HttpStatusCode::RequestHeaderFieldsTooLarge,
-            SurfStatus::UnavailableForLegalReasons => HttpStatusCode::UnavailableForLegalReasons,
-            SurfStatus::InternalServerError => HttpStatusCode::InternalServerError,
-            SurfStatus::NotImplemented => HttpStatusCode::NotImplemented,
-            SurfStatus::BadGateway => HttpStatusCode::BadGateway,
-            SurfStatus::ServiceUnavailable => HttpStatusCode::ServiceUnavailable,
-            SurfStatus::GatewayTimeout => HttpStatusCode::GatewayTimeout,
-            SurfStatus::HttpVersionNotSupported => HttpStatusCode::HttpVersionNotSupported,
-            SurfStatus::VariantAlsoNegotiates => HttpStatusCode::VariantAlsoNegotiates,
-            SurfStatus::InsufficientStorage => HttpStatusCode::InsufficientStorage,
-            SurfStatus::LoopDetected => HttpStatusCode::LoopDetected,
-            SurfStatus::NotExtended => HttpStatusCode::NotExtended,
-            SurfStatus::NetworkAuthenticationRequired => {
-                HttpStatusCode::NetworkAuthenticationRequired
-            }
-        }
+        self.status.clone()
     }
 
     fn header(&self, key: &str) -> Option<String> {
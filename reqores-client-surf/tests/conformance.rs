@@ -0,0 +1,13 @@
+use futures::executor::block_on;
+use reqores_client_surf::SurfClient;
+use reqores_test_support::conformance::Conformance;
+
+#[test]
+fn surf_client_passes_conformance() {
+    let client = SurfClient::new();
+
+    // http-types panics on statuses it does not know instead of returning an error.
+    let conformance = Conformance::new().skipping_unknown_status();
+
+    block_on(conformance.run(&client)).unwrap_or_else(|report| panic!("{}", report));
+}
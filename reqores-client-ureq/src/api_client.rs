@@ -0,0 +1,88 @@
+use reqores::{
+    BlockingClientBackend, HttpStatusCodeParseError, RawClientRequest, RawClientResponse,
+};
+use ureq::Agent;
+
+use crate::client_response::UreqClientResponse;
+
+/// The client sending requests with the [`Agent`] of ureq.
+///
+/// [`ClientRequest`](reqores::ClientRequest)s are called with [`BlockingClientBackend::call`].
+pub struct UreqClient(Agent);
+
+impl Default for UreqClient {
+    fn default() -> Self {
+        UreqClient::new()
+    }
+}
+
+impl UreqClient {
+    pub fn new() -> Self {
+        UreqClient(Agent::new())
+    }
+    pub fn with_agent(agent: Agent) -> Self {
+        UreqClient(agent)
+    }
+}
+
+impl BlockingClientBackend for UreqClient {
+    type Error = UreqClientError;
+
+    fn send(&self, raw_request: RawClientRequest) -> Result<RawClientResponse, UreqClientError> {
+        let mut request = self
+            .0
+            .request(raw_request.method.as_str(), &raw_request.url);
+        // ureq keeps only the last value of most headers, so repeated headers are combined.
+        let mut headers: Vec<(String, String)> = Vec::new();
+        for (k, v) in raw_request.headers {
+            match headers
+                .iter_mut()
+                .find(|(name, _)| name.eq_ignore_ascii_case(&k))
+            {
+                Some((_, value)) => *value = format!("{}, {}", value, v),
+                None => headers.push((k, v)),
+            }
+        }
+        for (k, v) in &headers {
+            request = request.set(k, v);
+        }
+
+        let result = match raw_request.body {
+            Some(body) => request.send_bytes(&body),
+            None => request.call(),
+        };
+        // ureq reports 4xx and 5xx as errors, while they are ordinary responses for reqores.
+        let response = match result {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+            Err(ureq::Error::Transport(transport)) => {
+                return Err(UreqClientError::Transport(Box::new(transport)))
+            }
+        };
+        let client_response = UreqClientResponse::new(response)?;
+
+        Ok(client_response.into_raw())
+    }
+}
+
+/// The error type for [`UreqClient`].
+#[derive(Debug)]
+pub enum UreqClientError {
+    /// The request failed to be sent or the response failed to be received.
+    Transport(Box<ureq::Transport>),
+    /// The response body failed to be read.
+    Io(std::io::Error),
+    /// The response has the status [`reqores::HttpStatusCode`] does not know.
+    Status(HttpStatusCodeParseError),
+}
+
+impl std::error::Error for UreqClientError {}
+
+impl core::fmt::Display for UreqClientError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            UreqClientError::Transport(e) => write!(f, "Transport Error: {}", e),
+            UreqClientError::Io(e) => write!(f, "IO Error: {}", e),
+            UreqClientError::Status(e) => write!(f, "Status Error: {}", e),
+        }
+    }
+}
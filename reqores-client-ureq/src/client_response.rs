@@ -0,0 +1,71 @@
+use std::io::Read;
+
+use reqores::{ClientResponse, HttpStatusCode, RawClientResponse};
+use ureq::Response;
+
+use crate::UreqClientError;
+
+pub struct UreqClientResponse {
+    body: Vec<u8>,
+    headers: Vec<(String, String)>,
+    status: HttpStatusCode,
+}
+
+impl UreqClientResponse {
+    pub fn new(response: Response) -> Result<Self, UreqClientError> {
+        let status =
+            HttpStatusCode::try_from(response.status()).map_err(UreqClientError::Status)?;
+        // headers_names lists a name for every header line, so repeated names are visited once.
+        let mut names: Vec<String> = Vec::new();
+        for name in response.headers_names() {
+            if !names.iter().any(|seen| seen.eq_ignore_ascii_case(&name)) {
+                names.push(name);
+            }
+        }
+        let headers = names
+            .into_iter()
+            .flat_map(|name| {
+                response
+                    .all(&name)
+                    .into_iter()
+                    .map(|value| (name.clone(), value.to_string()))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        let mut body = Vec::new();
+        response
+            .into_reader()
+            .read_to_end(&mut body)
+            .map_err(UreqClientError::Io)?;
+        Ok(Self {
+            body,
+            headers,
+            status,
+        })
+    }
+
+    pub fn into_raw(self) -> RawClientResponse {
+        RawClientResponse {
+            status: self.status,
+            headers: self.headers,
+            body: self.body,
+        }
+    }
+}
+
+impl ClientResponse for UreqClientResponse {
+    fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    fn status(&self) -> HttpStatusCode {
+        self.status.clone()
+    }
+
+    fn header(&self, key: &str) -> Option<String> {
+        self.headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value.clone())
+    }
+}
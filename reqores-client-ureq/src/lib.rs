@@ -0,0 +1,4 @@
+pub use api_client::{UreqClient, UreqClientError};
+
+mod api_client;
+mod client_response;
@@ -0,0 +1,23 @@
+use reqores_client_ureq::UreqClient;
+use reqores_test_support::conformance::Conformance;
+
+#[test]
+fn ureq_client_passes_conformance() {
+    let client = UreqClient::new();
+
+    // ureq follows up to 5 redirects by default.
+    let conformance = Conformance::new().following_redirects();
+
+    conformance
+        .run_blocking(&client)
+        .unwrap_or_else(|report| panic!("{}", report));
+}
+
+#[test]
+fn ureq_client_without_redirects_passes_conformance() {
+    let client = UreqClient::with_agent(ureq::AgentBuilder::new().redirects(0).build());
+
+    Conformance::new()
+        .run_blocking(&client)
+        .unwrap_or_else(|report| panic!("{}", report));
+}
@@ -0,0 +1,22 @@
+use actix_web::{web::Bytes, App, HttpRequest, HttpResponse, HttpServer};
+use reqores::{ServerRequest, ServerResponse, ServerResponseBuilder};
+use reqores_server_actix::{decode_request, encode_response, ActixServerRequest};
+
+fn handle(request: &ActixServerRequest) -> ServerResponse {
+    ServerResponseBuilder::new()
+        .with_header("Content-Type".to_string(), "text/plain".to_string())
+        .body([request.url().as_bytes(), b"\n", request.body()].concat())
+}
+
+async fn echo(request: HttpRequest, body: Bytes) -> actix_web::Result<HttpResponse> {
+    let request = decode_request(request, body)?;
+    Ok(encode_response(handle(&request)))
+}
+
+#[actix_web::main]
+async fn main() -> std::io::Result<()> {
+    HttpServer::new(|| App::new().default_service(actix_web::web::to(echo)))
+        .bind(("127.0.0.1", 8080))?
+        .run()
+        .await
+}
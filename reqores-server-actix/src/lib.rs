@@ -0,0 +1,5 @@
+pub use server_request::{decode_request, ActixServerRequest};
+pub use server_response::encode_response;
+
+mod server_request;
+mod server_response;
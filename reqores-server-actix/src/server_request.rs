@@ -0,0 +1,72 @@
+use std::net::IpAddr;
+
+use actix_web::{error, http::Method, web::Bytes, HttpRequest};
+use reqores::{HttpMethod, ServerRequest};
+
+pub struct ActixServerRequest {
+    request: HttpRequest,
+    method: HttpMethod,
+    url: String,
+    body: Bytes,
+}
+
+impl ActixServerRequest {
+    pub fn new(request: HttpRequest, body: Bytes) -> actix_web::Result<Self> {
+        let method = match *request.method() {
+            Method::GET => HttpMethod::Get,
+            Method::POST => HttpMethod::Post,
+            Method::PUT => HttpMethod::Put,
+            Method::DELETE => HttpMethod::Delete,
+            Method::PATCH => HttpMethod::Patch,
+            Method::HEAD => HttpMethod::Head,
+            Method::OPTIONS => HttpMethod::Options,
+            Method::CONNECT => HttpMethod::Connect,
+            Method::TRACE => HttpMethod::Trace,
+            ref method => {
+                return Err(error::ErrorNotImplemented(format!(
+                    "Unsupported Method: {}",
+                    method
+                )))
+            }
+        };
+        let url = request.full_url().to_string();
+        Ok(Self {
+            request,
+            method,
+            url,
+            body,
+        })
+    }
+
+    /// The HTTP method of the request.
+    pub fn method(&self) -> HttpMethod {
+        self.method
+    }
+
+    /// The URL of the request.
+    pub fn url(&self) -> String {
+        self.url.clone()
+    }
+}
+
+impl ServerRequest for ActixServerRequest {
+    fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    fn header(&self, key: &str) -> Option<String> {
+        self.request
+            .headers()
+            .get(key)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string())
+    }
+
+    fn remote_addr(&self) -> Option<IpAddr> {
+        self.request.peer_addr().map(|addr| addr.ip())
+    }
+}
+
+pub fn decode_request(request: HttpRequest, body: Bytes) -> actix_web::Result<ActixServerRequest> {
+    ActixServerRequest::new(request, body)
+}
@@ -0,0 +1,16 @@
+use actix_web::{http::StatusCode, HttpResponse};
+use reqores::ServerResponse;
+
+pub fn encode_response(server_response: ServerResponse) -> HttpResponse {
+    let status = server_response
+        .status
+        .and_then(|code| StatusCode::from_u16(u16::from(code)).ok())
+        .unwrap_or(StatusCode::OK);
+    let mut response = HttpResponse::build(status);
+
+    for (name, value) in server_response.headers {
+        response.append_header((name, value));
+    }
+
+    response.body(server_response.body.unwrap_or_default())
+}
@@ -0,0 +1,67 @@
+use actix_web::{
+    http::{Method, StatusCode},
+    test::{call_service, init_service, read_body, TestRequest},
+    web::{self, Bytes},
+    App, HttpRequest, HttpResponse,
+};
+use reqores::{HttpStatusCode, ServerRequest, ServerResponseBuilder};
+use reqores_server_actix::{decode_request, encode_response};
+
+async fn webhook(request: HttpRequest, body: Bytes) -> actix_web::Result<HttpResponse> {
+    let request = decode_request(request, body)?;
+    Ok(encode_response(
+        ServerResponseBuilder::new()
+            .with_status(HttpStatusCode::Created)
+            .with_header("Set-Cookie".to_string(), "a=1".to_string())
+            .with_header("Set-Cookie".to_string(), "b=2".to_string())
+            .with_header(
+                "X-Token".to_string(),
+                request.header("x-token").unwrap_or_default(),
+            )
+            .body([request.url().as_bytes(), b" ", request.body()].concat()),
+    ))
+}
+
+#[actix_web::test]
+async fn request_and_response_are_converted() {
+    let app = init_service(App::new().route("/hook", web::post().to(webhook))).await;
+
+    let request = TestRequest::post()
+        .uri("/hook?x=1")
+        .insert_header(("Host", "bot.example"))
+        .insert_header(("X-Token", "secret"))
+        .set_payload("payload")
+        .to_request();
+    let response = call_service(&app, request).await;
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(
+        response.headers().get_all("set-cookie").collect::<Vec<_>>(),
+        vec!["a=1", "b=2"]
+    );
+    assert_eq!(response.headers().get("x-token").unwrap(), "secret");
+    assert_eq!(
+        read_body(response).await,
+        Bytes::from_static(b"http://bot.example/hook?x=1 payload")
+    );
+}
+
+#[actix_web::test]
+async fn unsupported_method_is_not_implemented() {
+    let app = init_service(App::new().default_service(web::to(webhook))).await;
+
+    let request = TestRequest::default()
+        .method(Method::from_bytes(b"BREW").unwrap())
+        .uri("/")
+        .to_request();
+    let response = call_service(&app, request).await;
+
+    assert_eq!(response.status(), StatusCode::NOT_IMPLEMENTED);
+}
+
+#[actix_web::test]
+async fn missing_status_is_ok() {
+    let response = encode_response(ServerResponseBuilder::new().body_str("Hello"));
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
@@ -0,0 +1,21 @@
+use std::{future::Future, pin::Pin};
+
+use reqores::ServerResponse;
+
+use crate::{AxumServerRequest, AxumServerResponse};
+
+type HandlerFuture = Pin<Box<dyn Future<Output = AxumServerResponse> + Send>>;
+
+/// Wrap the reqores handler as an axum handler, to be passed to routing functions like `axum::routing::post`.
+pub fn handler<H, F>(
+    handler: H,
+) -> impl Fn(AxumServerRequest) -> HandlerFuture + Clone + Send + Sync + 'static
+where
+    H: Fn(AxumServerRequest) -> F + Clone + Send + Sync + 'static,
+    F: Future<Output = ServerResponse> + Send + 'static,
+{
+    move |request| {
+        let response = handler(request);
+        Box::pin(async move { AxumServerResponse(response.await) })
+    }
+}
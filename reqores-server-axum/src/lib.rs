@@ -0,0 +1,24 @@
+//! reqores server implementation for [axum](https://github.com/tokio-rs/axum).
+//!
+//! [`AxumServerRequest`] is an extractor and [`AxumServerResponse`] is a responder,
+//! so reqores handlers are mounted as axum routes with [`handler`].
+//!
+//! ```rust,no_run
+//! use axum::{routing::post, Router};
+//! use reqores::{ServerRequest, ServerResponse, ServerResponseBuilder};
+//! use reqores_server_axum::{handler, AxumServerRequest};
+//!
+//! async fn webhook(request: AxumServerRequest) -> ServerResponse {
+//!     ServerResponseBuilder::new().body_str(&format!("Hello, {}", request.url()))
+//! }
+//!
+//! let app: Router = Router::new().route("/webhook", post(handler(webhook)));
+//! ```
+
+pub use handler::handler;
+pub use server_request::AxumServerRequest;
+pub use server_response::{encode_response, AxumServerResponse};
+
+mod handler;
+mod server_request;
+mod server_response;
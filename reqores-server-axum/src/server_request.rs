@@ -0,0 +1,102 @@
+use std::net::{IpAddr, SocketAddr};
+
+use axum::{
+    async_trait,
+    body::Bytes,
+    extract::{ConnectInfo, FromRequest, Request},
+    http::{HeaderMap, Method, StatusCode},
+    response::{IntoResponse, Response},
+};
+use reqores::{HttpMethod, ServerRequest};
+
+/// The extractor collecting the request with [`Bytes`], so the body is limited by `DefaultBodyLimit`.
+///
+/// Requests with the method [`HttpMethod`] does not know are rejected with 501 Not Implemented.
+/// [`ServerRequest::remote_addr`] is known when the app is served with `into_make_service_with_connect_info::<SocketAddr>`.
+pub struct AxumServerRequest {
+    headers: HeaderMap,
+    method: HttpMethod,
+    url: String,
+    body: Bytes,
+    remote_addr: Option<IpAddr>,
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequest<S> for AxumServerRequest {
+    type Rejection = Response;
+
+    async fn from_request(request: Request, state: &S) -> Result<Self, Response> {
+        let method = match *request.method() {
+            Method::GET => HttpMethod::Get,
+            Method::POST => HttpMethod::Post,
+            Method::PUT => HttpMethod::Put,
+            Method::DELETE => HttpMethod::Delete,
+            Method::PATCH => HttpMethod::Patch,
+            Method::HEAD => HttpMethod::Head,
+            Method::OPTIONS => HttpMethod::Options,
+            Method::CONNECT => HttpMethod::Connect,
+            Method::TRACE => HttpMethod::Trace,
+            ref method => {
+                return Err((
+                    StatusCode::NOT_IMPLEMENTED,
+                    format!("Unsupported Method: {}", method),
+                )
+                    .into_response())
+            }
+        };
+        // Origin-form targets like "/path" are completed with the "Host" header.
+        let url = match (request.uri().scheme(), request.headers().get("Host")) {
+            (None, Some(host)) => format!(
+                "http://{}{}",
+                host.to_str().unwrap_or_default(),
+                request.uri()
+            ),
+            _ => request.uri().to_string(),
+        };
+        let headers = request.headers().clone();
+        let remote_addr = request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+        let body = Bytes::from_request(request, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+
+        Ok(Self {
+            headers,
+            method,
+            url,
+            body,
+            remote_addr,
+        })
+    }
+}
+
+impl AxumServerRequest {
+    /// The HTTP method of the request.
+    pub fn method(&self) -> HttpMethod {
+        self.method
+    }
+
+    /// The URL of the request.
+    pub fn url(&self) -> String {
+        self.url.clone()
+    }
+}
+
+impl ServerRequest for AxumServerRequest {
+    fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    fn header(&self, key: &str) -> Option<String> {
+        self.headers
+            .get(key)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string())
+    }
+
+    fn remote_addr(&self) -> Option<IpAddr> {
+        self.remote_addr
+    }
+}
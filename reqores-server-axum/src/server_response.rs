@@ -0,0 +1,47 @@
+use axum::{
+    body::Body,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use reqores::{HttpStatusCode, ServerResponse};
+
+pub fn encode_response(server_response: ServerResponse) -> axum::http::Result<Response> {
+    let mut response = Response::builder();
+    if let Some(code) = server_response.status {
+        response = response.status(u16::from(code));
+    }
+
+    for (name, value) in server_response.headers {
+        response = response.header(name, value);
+    }
+
+    response.body(Body::from(server_response.body.unwrap_or_default()))
+}
+
+/// The responder for [`ServerResponse`] and [`HttpStatusCode`].
+///
+/// The response failing to encode is replaced with empty 500 Internal Server Error.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AxumServerResponse(pub ServerResponse);
+
+impl From<ServerResponse> for AxumServerResponse {
+    fn from(response: ServerResponse) -> Self {
+        AxumServerResponse(response)
+    }
+}
+
+impl From<HttpStatusCode> for AxumServerResponse {
+    fn from(status: HttpStatusCode) -> Self {
+        AxumServerResponse(ServerResponse {
+            status: Some(status),
+            ..Default::default()
+        })
+    }
+}
+
+impl IntoResponse for AxumServerResponse {
+    fn into_response(self) -> Response {
+        encode_response(self.0)
+            .unwrap_or_else(|_| StatusCode::INTERNAL_SERVER_ERROR.into_response())
+    }
+}
@@ -0,0 +1,133 @@
+use std::net::SocketAddr;
+
+use axum::{
+    body::Body,
+    extract::{ConnectInfo, DefaultBodyLimit},
+    http::{Request, StatusCode},
+    response::Response,
+    routing::{get, post},
+    Router,
+};
+use http_body_util::BodyExt;
+use reqores::{HttpStatusCode, ServerRequest, ServerResponse, ServerResponseBuilder};
+use reqores_server_axum::{handler, AxumServerRequest, AxumServerResponse};
+use tower::ServiceExt;
+
+async fn webhook(request: AxumServerRequest) -> ServerResponse {
+    ServerResponseBuilder::new()
+        .with_status(HttpStatusCode::Created)
+        .with_header("Set-Cookie".to_string(), "a=1".to_string())
+        .with_header("Set-Cookie".to_string(), "b=2".to_string())
+        .with_header(
+            "X-Token".to_string(),
+            request.header("x-token").unwrap_or_default(),
+        )
+        .body([request.url().as_bytes(), b" ", request.body()].concat())
+}
+
+async fn body_string(response: Response) -> String {
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn handler_is_mounted_as_route() {
+    let app = Router::new().route("/hook", post(handler(webhook)));
+
+    let response = app
+        .oneshot(
+            Request::post("/hook?x=1")
+                .header("Host", "bot.example")
+                .header("X-Token", "secret")
+                .body(Body::from("payload"))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(
+        response
+            .headers()
+            .get_all("set-cookie")
+            .iter()
+            .collect::<Vec<_>>(),
+        vec!["a=1", "b=2"]
+    );
+    assert_eq!(response.headers()["x-token"], "secret");
+    assert_eq!(
+        body_string(response).await,
+        "http://bot.example/hook?x=1 payload"
+    );
+}
+
+#[tokio::test]
+async fn remote_addr_comes_from_connect_info() {
+    let app = Router::new().route(
+        "/ip",
+        get(|request: AxumServerRequest| async move { format!("{:?}", request.remote_addr()) }),
+    );
+
+    let mut request = Request::get("/ip").body(Body::empty()).unwrap();
+    request
+        .extensions_mut()
+        .insert(ConnectInfo(SocketAddr::from(([127, 0, 0, 1], 8080))));
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(body_string(response).await, "Some(127.0.0.1)");
+
+    let response = app
+        .oneshot(Request::get("/ip").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(body_string(response).await, "None");
+}
+
+#[tokio::test]
+async fn status_code_is_a_response() {
+    let app = Router::new().route(
+        "/teapot",
+        get(|| async { AxumServerResponse::from(HttpStatusCode::ImATeapot) }),
+    );
+
+    let response = app
+        .oneshot(Request::get("/teapot").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::IM_A_TEAPOT);
+}
+
+#[tokio::test]
+async fn body_limit_applies_to_extractor() {
+    let app = Router::new()
+        .route("/hook", post(handler(webhook)))
+        .layer(DefaultBodyLimit::max(4));
+
+    let response = app
+        .oneshot(Request::post("/hook").body(Body::from("12345")).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn invalid_response_is_internal_server_error() {
+    let app = Router::new().route(
+        "/bad",
+        get(|| async {
+            AxumServerResponse(
+                ServerResponseBuilder::new()
+                    .with_header("X-Bad".to_string(), "a\nb".to_string())
+                    .end(),
+            )
+        }),
+    );
+
+    let response = app
+        .oneshot(Request::get("/bad").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
@@ -0,0 +1,35 @@
+//! reqores server implementation with [hyper](https://hyper.rs).
+//!
+//! ```rust,no_run
+//! use hyper_util::{
+//!     rt::{TokioExecutor, TokioIo},
+//!     server::conn::auto::Builder,
+//! };
+//! use reqores::{ServerRequest, ServerResponseBuilder};
+//! use reqores_server_hyper::{service, HyperServerRequest};
+//! use tokio::net::TcpListener;
+//!
+//! async fn handler(request: HyperServerRequest) -> reqores::ServerResponse {
+//!     ServerResponseBuilder::new().body_str(&format!("Hello, {}", request.url()))
+//! }
+//!
+//! # async fn run() -> std::io::Result<()> {
+//! let listener = TcpListener::bind("127.0.0.1:8080").await?;
+//! loop {
+//!     let (stream, _) = listener.accept().await?;
+//!     tokio::spawn(async move {
+//!         let _ = Builder::new(TokioExecutor::new())
+//!             .serve_connection(TokioIo::new(stream), service(handler))
+//!             .await;
+//!     });
+//! }
+//! # }
+//! ```
+
+pub use server_request::{decode_request, DecodeError, HyperServerRequest};
+pub use server_response::encode_response;
+pub use service::{service, ReqoresService};
+
+mod server_request;
+mod server_response;
+mod service;
@@ -0,0 +1,113 @@
+use http_body_util::{BodyExt, LengthLimitError, Limited};
+use hyper::{body::Body, http::request::Parts, Method, Request};
+use reqores::{HttpMethod, ServerRequest};
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+pub struct HyperServerRequest {
+    parts: Parts,
+    method: HttpMethod,
+    url: String,
+    body: Vec<u8>,
+}
+
+impl HyperServerRequest {
+    pub async fn new<B>(request: Request<B>, body_limit: usize) -> Result<Self, DecodeError>
+    where
+        B: Body,
+        B::Error: Into<BoxError>,
+    {
+        let (parts, body) = request.into_parts();
+        let method = match parts.method {
+            Method::GET => HttpMethod::Get,
+            Method::POST => HttpMethod::Post,
+            Method::PUT => HttpMethod::Put,
+            Method::DELETE => HttpMethod::Delete,
+            Method::PATCH => HttpMethod::Patch,
+            Method::HEAD => HttpMethod::Head,
+            Method::OPTIONS => HttpMethod::Options,
+            Method::CONNECT => HttpMethod::Connect,
+            Method::TRACE => HttpMethod::Trace,
+            ref method => return Err(DecodeError::UnsupportedMethod(method.to_string())),
+        };
+        // Origin-form targets like "/path" are completed with the "Host" header.
+        let url = match (parts.uri.scheme(), parts.headers.get("Host")) {
+            (None, Some(host)) => {
+                format!("http://{}{}", host.to_str().unwrap_or_default(), parts.uri)
+            }
+            _ => parts.uri.to_string(),
+        };
+
+        let body = match Limited::new(body, body_limit).collect().await {
+            Ok(collected) => collected.to_bytes().to_vec(),
+            Err(e) if e.is::<LengthLimitError>() => return Err(DecodeError::BodyTooLarge),
+            Err(e) => return Err(DecodeError::Body(e)),
+        };
+
+        Ok(Self {
+            parts,
+            method,
+            url,
+            body,
+        })
+    }
+
+    /// The HTTP method of the request.
+    pub fn method(&self) -> HttpMethod {
+        self.method
+    }
+
+    /// The URL of the request.
+    pub fn url(&self) -> String {
+        self.url.clone()
+    }
+}
+
+impl ServerRequest for HyperServerRequest {
+    fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    fn header(&self, key: &str) -> Option<String> {
+        self.parts
+            .headers
+            .get(key)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.to_string())
+    }
+}
+
+/// Collect the request, failing if the body is longer than `body_limit` bytes.
+pub async fn decode_request<B>(
+    request: Request<B>,
+    body_limit: usize,
+) -> Result<HyperServerRequest, DecodeError>
+where
+    B: Body,
+    B::Error: Into<BoxError>,
+{
+    HyperServerRequest::new(request, body_limit).await
+}
+
+/// The error type for decoding a hyper request.
+#[derive(Debug)]
+pub enum DecodeError {
+    /// The method is not the one [`HttpMethod`] knows.
+    UnsupportedMethod(String),
+    /// The body is longer than the limit.
+    BodyTooLarge,
+    /// Reading the body failed.
+    Body(BoxError),
+}
+
+impl std::error::Error for DecodeError {}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DecodeError::UnsupportedMethod(method) => write!(f, "Unsupported Method: {}", method),
+            DecodeError::BodyTooLarge => write!(f, "Body Too Large"),
+            DecodeError::Body(e) => write!(f, "Body Error: {}", e),
+        }
+    }
+}
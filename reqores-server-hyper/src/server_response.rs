@@ -0,0 +1,18 @@
+use http_body_util::Full;
+use hyper::{body::Bytes, Response};
+use reqores::ServerResponse;
+
+pub fn encode_response(
+    server_response: ServerResponse,
+) -> hyper::http::Result<Response<Full<Bytes>>> {
+    let mut response = Response::builder();
+    if let Some(code) = server_response.status {
+        response = response.status(u16::from(code));
+    }
+
+    for (name, value) in server_response.headers {
+        response = response.header(name, value);
+    }
+
+    response.body(Full::from(server_response.body.unwrap_or_default()))
+}
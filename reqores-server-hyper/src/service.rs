@@ -0,0 +1,87 @@
+use std::{convert::Infallible, future::Future, pin::Pin, sync::Arc};
+
+use http_body_util::Full;
+use hyper::{
+    body::{Body, Bytes},
+    service::Service,
+    Request, Response,
+};
+use reqores::{HttpStatusCode, ServerResponse, ServerResponseBuilder};
+
+use crate::{decode_request, encode_response, DecodeError, HyperServerRequest};
+
+/// Wrap the reqores handler as a hyper service, collecting bodies up to 1 MiB by default.
+pub fn service<H, F>(handler: H) -> ReqoresService<H>
+where
+    H: Fn(HyperServerRequest) -> F,
+    F: Future<Output = ServerResponse>,
+{
+    ReqoresService {
+        handler: Arc::new(handler),
+        body_limit: 1024 * 1024,
+    }
+}
+
+/// The hyper service calling the reqores handler.
+///
+/// Requests failing to decode are answered with 400, 413 or 501 without calling the handler,
+/// and responses failing to encode are replaced with empty 500 Internal Server Error.
+pub struct ReqoresService<H> {
+    handler: Arc<H>,
+    body_limit: usize,
+}
+
+impl<H> ReqoresService<H> {
+    /// Set the maximum length of request bodies.
+    pub fn with_body_limit(mut self, body_limit: usize) -> Self {
+        self.body_limit = body_limit;
+        self
+    }
+}
+
+impl<H> Clone for ReqoresService<H> {
+    fn clone(&self) -> Self {
+        ReqoresService {
+            handler: self.handler.clone(),
+            body_limit: self.body_limit,
+        }
+    }
+}
+
+impl<H, F, B> Service<Request<B>> for ReqoresService<H>
+where
+    H: Fn(HyperServerRequest) -> F + Send + Sync + 'static,
+    F: Future<Output = ServerResponse> + Send + 'static,
+    B: Body + Send + 'static,
+    B::Data: Send,
+    B::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    type Response = Response<Full<Bytes>>;
+    type Error = Infallible;
+    type Future = Pin<Box<dyn Future<Output = Result<Response<Full<Bytes>>, Infallible>> + Send>>;
+
+    fn call(&self, request: Request<B>) -> Self::Future {
+        let handler = self.handler.clone();
+        let body_limit = self.body_limit;
+        Box::pin(async move {
+            let server_response = match decode_request(request, body_limit).await {
+                Ok(request) => handler(request).await,
+                Err(e) => {
+                    let status = match e {
+                        DecodeError::UnsupportedMethod(_) => HttpStatusCode::NotImplemented,
+                        DecodeError::BodyTooLarge => HttpStatusCode::PayloadTooLarge,
+                        DecodeError::Body(_) => HttpStatusCode::BadRequest,
+                    };
+                    ServerResponseBuilder::new()
+                        .with_status(status)
+                        .body_str(&e.to_string())
+                }
+            };
+            Ok(encode_response(server_response).unwrap_or_else(|_| {
+                let mut response = Response::new(Full::default());
+                *response.status_mut() = hyper::StatusCode::INTERNAL_SERVER_ERROR;
+                response
+            }))
+        })
+    }
+}
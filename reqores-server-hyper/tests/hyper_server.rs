@@ -0,0 +1,145 @@
+use std::future::Future;
+
+use http_body_util::{BodyExt, Empty, Full};
+use hyper::{
+    body::{Body, Bytes},
+    server::conn::http1,
+    service::Service,
+    Method, Request, Response, StatusCode,
+};
+use hyper_util::{
+    client::legacy::Client,
+    rt::{TokioExecutor, TokioIo},
+};
+use reqores::{HttpStatusCode, ServerRequest, ServerResponse, ServerResponseBuilder};
+use reqores_server_hyper::{encode_response, service, HyperServerRequest};
+use tokio::net::TcpListener;
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+        .block_on(future)
+}
+
+async fn handler(request: HyperServerRequest) -> ServerResponse {
+    ServerResponseBuilder::new()
+        .with_status(HttpStatusCode::Created)
+        .with_header("Set-Cookie".to_string(), "a=1".to_string())
+        .with_header("Set-Cookie".to_string(), "b=2".to_string())
+        .with_header(
+            "X-Token".to_string(),
+            request.header("x-token").unwrap_or_default(),
+        )
+        .body([request.url().as_bytes(), b" ", request.body()].concat())
+}
+
+async fn body_string<B>(response: Response<B>) -> String
+where
+    B: Body,
+    B::Error: std::fmt::Debug,
+{
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    String::from_utf8(body.to_vec()).unwrap()
+}
+
+#[test]
+fn service_calls_handler() {
+    block_on(async {
+        let request = Request::post("/hook?x=1")
+            .header("Host", "bot.example")
+            .header("X-Token", "secret")
+            .body(Full::new(Bytes::from("payload")))
+            .unwrap();
+
+        let response = service(handler).call(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(
+            response
+                .headers()
+                .get_all("set-cookie")
+                .iter()
+                .collect::<Vec<_>>(),
+            vec!["a=1", "b=2"]
+        );
+        assert_eq!(response.headers()["x-token"], "secret");
+        assert_eq!(
+            body_string(response).await,
+            "http://bot.example/hook?x=1 payload"
+        );
+    });
+}
+
+#[test]
+fn oversized_body_is_rejected() {
+    block_on(async {
+        let request = Request::post("/")
+            .header("Host", "bot.example")
+            .body(Full::new(Bytes::from("12345")))
+            .unwrap();
+
+        let response = service(handler)
+            .with_body_limit(4)
+            .call(request)
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    });
+}
+
+#[test]
+fn unsupported_method_is_rejected() {
+    block_on(async {
+        let request = Request::builder()
+            .method(Method::from_bytes(b"BREW").unwrap())
+            .uri("/")
+            .body(Empty::<Bytes>::new())
+            .unwrap();
+
+        let response = service(handler).call(request).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_IMPLEMENTED);
+    });
+}
+
+#[test]
+fn encode_response_rejects_invalid_headers() {
+    let response = ServerResponseBuilder::new()
+        .with_header("X-Bad".to_string(), "a\nb".to_string())
+        .end();
+
+    assert!(encode_response(response).is_err());
+}
+
+#[test]
+fn serves_over_tcp() {
+    block_on(async {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service(handler))
+                .await
+                .unwrap();
+        });
+
+        let request = Request::put(format!("http://{}/items", addr))
+            .body(Full::new(Bytes::from("item")))
+            .unwrap();
+        let response = Client::builder(TokioExecutor::new())
+            .build_http()
+            .request(request)
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(
+            body_string(response).await,
+            format!("http://{}/items item", addr)
+        );
+    });
+}
@@ -0,0 +1,92 @@
+use std::io::{self, Read, Write};
+
+use reqores::{
+    wire::{BodyEncoding, WireError, WireLimits, WireRequest},
+    HttpMethod, HttpStatusCode, ServerResponse, ServerResponseBuilder,
+};
+
+/// Read a request from the stream, answering "Expect: 100-continue" on the way.
+pub fn decode_request(
+    stream: &mut (impl Read + Write),
+    limits: &WireLimits,
+) -> Result<WireRequest, DecodeError> {
+    let mut buffer = Vec::new();
+    let mut chunk = [0; 16 * 1024];
+    let mut continued = false;
+    loop {
+        match WireRequest::parse(&buffer, limits) {
+            Ok((request, _)) => return Ok(request),
+            Err(WireError::Incomplete) => {}
+            Err(e) => return Err(DecodeError::Wire(e)),
+        }
+        if !continued && expects_continue(&buffer) {
+            stream.write_all(b"HTTP/1.1 100 Continue\r\n\r\n")?;
+            continued = true;
+        }
+        let read = stream.read(&mut chunk)?;
+        if read == 0 {
+            return Err(DecodeError::Io(io::ErrorKind::UnexpectedEof.into()));
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+}
+
+/// Serialize the response to the request with the method.
+///
+/// The body is left out for HEAD requests,
+/// and the response with invalid headers is replaced with empty 500 Internal Server Error.
+pub fn encode_response(server_response: ServerResponse, method: HttpMethod) -> Vec<u8> {
+    let mut output = server_response
+        .to_wire(BodyEncoding::ContentLength)
+        .unwrap_or_else(|_| {
+            ServerResponseBuilder::new()
+                .with_status(HttpStatusCode::InternalServerError)
+                .end()
+                .to_wire(BodyEncoding::ContentLength)
+                .expect("the empty response should be valid")
+        });
+    if method == HttpMethod::Head {
+        if let Some(end) = output.windows(4).position(|window| window == b"\r\n\r\n") {
+            output.truncate(end + 4);
+        }
+    }
+    output
+}
+
+fn expects_continue(buffer: &[u8]) -> bool {
+    let Some(end) = buffer.windows(4).position(|window| window == b"\r\n\r\n") else {
+        return false;
+    };
+    String::from_utf8_lossy(&buffer[..end])
+        .split("\r\n")
+        .filter_map(|line| line.split_once(':'))
+        .any(|(name, value)| {
+            name.eq_ignore_ascii_case("Expect") && value.trim().eq_ignore_ascii_case("100-continue")
+        })
+}
+
+/// The error type for reading a request.
+#[derive(Debug)]
+pub enum DecodeError {
+    /// The stream failed or closed before the request ended.
+    Io(io::Error),
+    /// The request is malformed or exceeds the limits.
+    Wire(WireError),
+}
+
+impl From<io::Error> for DecodeError {
+    fn from(error: io::Error) -> Self {
+        DecodeError::Io(error)
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+impl core::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            DecodeError::Io(e) => write!(f, "IO Error: {}", e),
+            DecodeError::Wire(e) => write!(f, "Wire Error: {}", e),
+        }
+    }
+}
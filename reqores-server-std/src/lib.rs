@@ -0,0 +1,17 @@
+//! reqores server implementation with [`std::net`], to run handlers locally, in Docker, or on a VPS.
+//!
+//! ```rust,no_run
+//! use reqores::{ServerRequest, ServerResponseBuilder};
+//! use reqores_server_std::StdServer;
+//!
+//! StdServer::bind("127.0.0.1:8080")
+//!     .unwrap()
+//!     .serve(|request| ServerResponseBuilder::new().body_str(&format!("Hello, {}", request.url())))
+//!     .unwrap();
+//! ```
+
+pub use codec::{decode_request, encode_response, DecodeError};
+pub use server::StdServer;
+
+mod codec;
+mod server;
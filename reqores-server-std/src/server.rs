@@ -0,0 +1,115 @@
+use std::{
+    io::{self, Write},
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    panic::{self, AssertUnwindSafe},
+    sync::Arc,
+    time::Duration,
+};
+
+use reqores::{
+    wire::{WireLimits, WireRequest},
+    HttpMethod, HttpStatusCode, ServerResponse, ServerResponseBuilder,
+};
+
+use crate::{decode_request, encode_response, DecodeError};
+
+/// The blocking HTTP/1.1 server calling the handler on a thread per connection.
+///
+/// Every connection is closed after a response, and the handler panicking is answered with 500 Internal Server Error.
+pub struct StdServer {
+    listener: TcpListener,
+    limits: WireLimits,
+    timeout: Duration,
+}
+
+impl StdServer {
+    /// Bind the server to the address, like "127.0.0.1:8080" or "0.0.0.0:0".
+    pub fn bind(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        Ok(StdServer {
+            listener: TcpListener::bind(addr)?,
+            limits: WireLimits::default(),
+            timeout: Duration::from_secs(30),
+        })
+    }
+
+    /// Set the limits applied to incoming requests.
+    pub fn with_limits(mut self, limits: WireLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Set the timeout for reading the request and writing the response.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// The address the server is bound to, useful after binding to the port 0.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Serve the requests with the handler forever.
+    pub fn serve(
+        self,
+        handler: impl Fn(&WireRequest) -> ServerResponse + Send + Sync + 'static,
+    ) -> io::Result<()> {
+        let handler = Arc::new(handler);
+        let limits = Arc::new(self.limits);
+        for stream in self.listener.incoming() {
+            // Accepting fails on transient conditions like running out of file descriptors.
+            let Ok(stream) = stream else {
+                continue;
+            };
+            let handler = handler.clone();
+            let limits = limits.clone();
+            let timeout = self.timeout;
+            std::thread::spawn(move || {
+                let _ = handle_connection(stream, &*handler, &limits, timeout);
+            });
+        }
+        Ok(())
+    }
+}
+
+fn handle_connection(
+    mut stream: TcpStream,
+    handler: &impl Fn(&WireRequest) -> ServerResponse,
+    limits: &WireLimits,
+    timeout: Duration,
+) -> io::Result<()> {
+    stream.set_read_timeout(Some(timeout))?;
+    stream.set_write_timeout(Some(timeout))?;
+
+    let (mut response, method) = match decode_request(&mut stream, limits) {
+        Ok(request) => {
+            let request = match stream.peer_addr() {
+                Ok(peer) => request.with_remote_addr(peer.ip()),
+                Err(_) => request,
+            };
+            let response = panic::catch_unwind(AssertUnwindSafe(|| handler(&request)))
+                .unwrap_or_else(|_| {
+                    ServerResponseBuilder::new()
+                        .with_status(HttpStatusCode::InternalServerError)
+                        .end()
+                });
+            (response, request.method())
+        }
+        Err(DecodeError::Wire(e)) => (
+            ServerResponseBuilder::new()
+                .with_status(e.status().unwrap_or(HttpStatusCode::BadRequest))
+                .body_str(&e.to_string()),
+            HttpMethod::Get,
+        ),
+        Err(DecodeError::Io(e)) => return Err(e),
+    };
+
+    response
+        .headers
+        .retain(|(name, _)| !name.eq_ignore_ascii_case("Connection"));
+    response
+        .headers
+        .push(("Connection".to_string(), "close".to_string()));
+    stream.write_all(&encode_response(response, method))?;
+    stream.flush()
+}
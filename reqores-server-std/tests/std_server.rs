@@ -0,0 +1,154 @@
+use std::{
+    io::{Read, Write},
+    net::{SocketAddr, TcpStream},
+};
+
+use reqores::{wire::WireLimits, HttpMethod, HttpStatusCode, ServerRequest, ServerResponseBuilder};
+use reqores_server_std::StdServer;
+
+fn start(server: StdServer) -> SocketAddr {
+    let addr = server.local_addr().unwrap();
+    std::thread::spawn(move || {
+        server
+            .serve(|request| {
+                if request.url().ends_with("/panic") {
+                    panic!("handler panicked");
+                }
+                let method = match request.method() {
+                    HttpMethod::Post => "POST",
+                    HttpMethod::Head => "HEAD",
+                    _ => "OTHER",
+                };
+                ServerResponseBuilder::new()
+                    .with_status(HttpStatusCode::Created)
+                    .with_header("X-Method".to_string(), method.to_string())
+                    .with_header(
+                        "X-Remote-Addr".to_string(),
+                        format!("{:?}", request.remote_addr()),
+                    )
+                    .body([request.url().as_bytes(), b" ", request.body()].concat())
+            })
+            .unwrap()
+    });
+    addr
+}
+
+fn send(addr: SocketAddr, request: &[u8]) -> String {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream.write_all(request).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+#[test]
+fn handler_answers_requests() {
+    let addr = start(StdServer::bind("127.0.0.1:0").unwrap());
+
+    let response = send(
+        addr,
+        b"POST /hello HTTP/1.1\r\nHost: local\r\nContent-Length: 4\r\n\r\nbody",
+    );
+
+    assert!(
+        response.starts_with("HTTP/1.1 201 Created\r\n"),
+        "{}",
+        response
+    );
+    assert!(response.contains("X-Method: POST\r\n"), "{}", response);
+    assert!(
+        response.contains("X-Remote-Addr: Some(127.0.0.1)\r\n"),
+        "{}",
+        response
+    );
+    assert!(response.contains("Connection: close\r\n"), "{}", response);
+    assert!(
+        response.ends_with("\r\n\r\nhttp://local/hello body"),
+        "{}",
+        response
+    );
+}
+
+#[test]
+fn chunked_request_with_expect_continue() {
+    let addr = start(StdServer::bind("127.0.0.1:0").unwrap());
+    let mut stream = TcpStream::connect(addr).unwrap();
+
+    stream
+        .write_all(b"POST / HTTP/1.1\r\nHost: local\r\nExpect: 100-continue\r\nTransfer-Encoding: chunked\r\n\r\n")
+        .unwrap();
+    let mut interim = [0; 25];
+    stream.read_exact(&mut interim).unwrap();
+    assert_eq!(&interim, b"HTTP/1.1 100 Continue\r\n\r\n");
+
+    stream.write_all(b"3\r\nabc\r\n0\r\n\r\n").unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    assert!(response.ends_with("http://local/ abc"), "{}", response);
+}
+
+#[test]
+fn head_response_has_no_body() {
+    let addr = start(StdServer::bind("127.0.0.1:0").unwrap());
+
+    let response = send(addr, b"HEAD /x HTTP/1.1\r\nHost: local\r\n\r\n");
+
+    assert!(response.contains("X-Method: HEAD\r\n"), "{}", response);
+    assert!(response.contains("Content-Length: 15\r\n"), "{}", response);
+    assert!(response.ends_with("\r\n\r\n"), "{}", response);
+}
+
+#[test]
+fn malformed_requests_are_rejected() {
+    let addr = start(StdServer::bind("127.0.0.1:0").unwrap());
+
+    let response = send(addr, b"GET / HTTP/1.1\r\n\r\n");
+    assert!(
+        response.starts_with("HTTP/1.1 400 Bad Request\r\n"),
+        "{}",
+        response
+    );
+
+    let response = send(addr, b"BREW / HTTP/1.1\r\nHost: local\r\n\r\n");
+    assert!(
+        response.starts_with("HTTP/1.1 501 Not Implemented\r\n"),
+        "{}",
+        response
+    );
+}
+
+#[test]
+fn limits_are_enforced() {
+    let addr = start(
+        StdServer::bind("127.0.0.1:0")
+            .unwrap()
+            .with_limits(WireLimits {
+                max_body_size: 2,
+                ..Default::default()
+            }),
+    );
+
+    let response = send(
+        addr,
+        b"POST / HTTP/1.1\r\nHost: local\r\nContent-Length: 3\r\n\r\nabc",
+    );
+
+    assert!(
+        response.starts_with("HTTP/1.1 413 Payload Too Large\r\n"),
+        "{}",
+        response
+    );
+}
+
+#[test]
+fn panicking_handler_is_internal_server_error() {
+    let addr = start(StdServer::bind("127.0.0.1:0").unwrap());
+
+    let response = send(addr, b"GET /panic HTTP/1.1\r\nHost: local\r\n\r\n");
+
+    assert!(
+        response.starts_with("HTTP/1.1 500 Internal Server Error\r\n"),
+        "{}",
+        response
+    );
+}
@@ -0,0 +1,379 @@
+//! The conformance suite every [`ClientBackend`] should pass.
+//!
+//! The suite starts a loopback HTTP server and checks that the backend maps methods, headers, bodies,
+//! binary payloads, statuses, redirects and errors the same way as the others.
+//!
+//! ```rust,no_run
+//! # async fn run(backend: impl reqores::ClientBackend<Error = std::io::Error>) {
+//! use reqores_test_support::conformance::Conformance;
+//!
+//! Conformance::new().run(&backend).await.unwrap();
+//! # }
+//! ```
+//!
+//! [`BlockingClientBackend`]s run the same suite with [`Conformance::run_blocking`].
+
+use std::{
+    fmt::Debug,
+    future::Future,
+    pin::pin,
+    sync::Arc,
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+};
+
+use reqores::{
+    BlockingClientBackend, ClientBackend, HttpMethod, HttpStatusCode, RawClientRequest,
+    RawClientResponse,
+};
+use serde_json::{json, Value};
+
+use crate::loopback::{LoopbackRequest, LoopbackResponse, LoopbackServer};
+
+/// The statuses checked to be mapped as-is.
+const STATUSES: [HttpStatusCode; 8] = [
+    HttpStatusCode::Ok,
+    HttpStatusCode::Created,
+    HttpStatusCode::NoContent,
+    HttpStatusCode::BadRequest,
+    HttpStatusCode::Notfound,
+    HttpStatusCode::ImATeapot,
+    HttpStatusCode::InternalServerError,
+    HttpStatusCode::ServiceUnavailable,
+];
+
+/// The configuration of the conformance suite.
+#[derive(Clone, Debug, Default)]
+pub struct Conformance {
+    follows_redirects: bool,
+    skips_unknown_status: bool,
+}
+
+impl Conformance {
+    /// Create a new [`Conformance`] expecting redirects to be returned as-is.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Expect redirects to be followed by the backend, like `fetch` does.
+    pub fn following_redirects(mut self) -> Self {
+        self.follows_redirects = true;
+        self
+    }
+
+    /// Skip the check that a status unknown to [`HttpStatusCode`] is an error,
+    /// for backends whose HTTP stack cannot represent such a status at all.
+    pub fn skipping_unknown_status(mut self) -> Self {
+        self.skips_unknown_status = true;
+        self
+    }
+
+    /// Run every check against the backend, reporting all failures at once.
+    pub async fn run<B>(&self, backend: &B) -> Result<(), String>
+    where
+        B: ClientBackend,
+        B::Error: Debug,
+    {
+        let server = LoopbackServer::start(route)
+            .map_err(|e| format!("failed to start loopback server: {}", e))?;
+        let mut failures = Vec::new();
+
+        for method in [
+            HttpMethod::Get,
+            HttpMethod::Post,
+            HttpMethod::Put,
+            HttpMethod::Delete,
+            HttpMethod::Patch,
+            HttpMethod::Options,
+        ] {
+            let result = inspect(backend, request(method, server.url("/inspect"))).await;
+            if let Err(e) = result
+                .and_then(|report| expect_eq("method", &report["method"], &json!(method.as_str())))
+            {
+                failures.push(format!("method {}: {}", method, e));
+            }
+        }
+
+        let result = send(backend, request(HttpMethod::Head, server.url("/echo"))).await;
+        if let Err(e) = result.and_then(|response| {
+            expect_eq("status", &response.status, &HttpStatusCode::Ok)?;
+            expect_eq("body", &response.body, &Vec::new())
+        }) {
+            failures.push(format!("method HEAD: {}", e));
+        }
+
+        let mut headers_request = request(HttpMethod::Get, server.url("/inspect"));
+        headers_request.headers = vec![
+            ("X-Single".to_string(), "single".to_string()),
+            ("X-Multi".to_string(), "1".to_string()),
+            ("X-Multi".to_string(), "2".to_string()),
+        ];
+        let result = inspect(backend, headers_request).await;
+        if let Err(e) = result.and_then(|report| {
+            let values = |key: &str| {
+                report["headers"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter(|pair| {
+                        pair[0]
+                            .as_str()
+                            .is_some_and(|name| name.eq_ignore_ascii_case(key))
+                    })
+                    .filter_map(|pair| pair[1].as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            expect_eq("X-Single", &values("X-Single"), &"single".to_string())?;
+            expect_eq("X-Multi", &values("X-Multi"), &"1, 2".to_string())
+        }) {
+            failures.push(format!("request headers: {}", e));
+        }
+
+        let mut body_request = request(HttpMethod::Post, server.url("/inspect"));
+        body_request.body = Some("{\"text\":\"héllo\"}".as_bytes().to_vec());
+        let result = inspect(backend, body_request).await;
+        if let Err(e) = result.and_then(|report| {
+            expect_eq(
+                "body",
+                &report["body"],
+                &json!("{\"text\":\"héllo\"}".as_bytes()),
+            )
+        }) {
+            failures.push(format!("request body: {}", e));
+        }
+
+        let payload: Vec<u8> = (0..=255).collect();
+        let mut binary_request = request(HttpMethod::Post, server.url("/echo"));
+        binary_request.body = Some(payload.clone());
+        let result = send(backend, binary_request).await;
+        if let Err(e) = result.and_then(|response| expect_eq("body", &response.body, &payload)) {
+            failures.push(format!("binary payload: {}", e));
+        }
+
+        for status in STATUSES {
+            let code = u16::from(status.clone());
+            let result = send(
+                backend,
+                request(HttpMethod::Get, server.url(&format!("/status/{}", code))),
+            )
+            .await;
+            if let Err(e) = result.and_then(|response| {
+                expect_eq("status", &response.status, &status)?;
+                expect_eq("body", &response.body, &status_body(code))
+            }) {
+                failures.push(format!("status {}: {}", code, e));
+            }
+        }
+
+        let result = send(backend, request(HttpMethod::Get, server.url("/headers"))).await;
+        if let Err(e) = result.and_then(|response| {
+            let values = |key: &str| {
+                response
+                    .headers
+                    .iter()
+                    .filter(|(name, _)| name.eq_ignore_ascii_case(key))
+                    .map(|(_, value)| value.as_str())
+                    .collect::<Vec<_>>()
+            };
+            expect_eq("X-Custom", &values("X-Custom"), &vec!["custom"])?;
+            expect_eq("Set-Cookie", &values("Set-Cookie"), &vec!["a=1", "b=2"])
+        }) {
+            failures.push(format!("response headers: {}", e));
+        }
+
+        let result = send(backend, request(HttpMethod::Get, server.url("/redirect"))).await;
+        if let Err(e) = result.and_then(|response| {
+            if self.follows_redirects {
+                expect_eq("status", &response.status, &HttpStatusCode::Ok)?;
+                expect_eq("body", &response.body, &status_body(200))
+            } else {
+                expect_eq("status", &response.status, &HttpStatusCode::Found)?;
+                expect_eq(
+                    "location",
+                    &response
+                        .headers
+                        .iter()
+                        .find(|(name, _)| name.eq_ignore_ascii_case("Location"))
+                        .map(|(_, value)| value.as_str()),
+                    &Some("/status/200"),
+                )
+            }
+        }) {
+            failures.push(format!("redirect: {}", e));
+        }
+
+        if !self.skips_unknown_status {
+            let result = send(backend, request(HttpMethod::Get, server.url("/status/599"))).await;
+            if let Ok(response) = result {
+                failures.push(format!(
+                    "unknown status: expected an error, got status {}",
+                    u16::from(response.status)
+                ));
+            }
+        }
+
+        let closed = {
+            let listener = std::net::TcpListener::bind("127.0.0.1:0")
+                .map_err(|e| format!("failed to reserve a closed port: {}", e))?;
+            listener.local_addr().map_err(|e| e.to_string())?
+        };
+        let result = send(
+            backend,
+            request(HttpMethod::Get, format!("http://{}/", closed)),
+        )
+        .await;
+        if let Ok(response) = result {
+            failures.push(format!(
+                "connection refused: expected an error, got status {}",
+                u16::from(response.status)
+            ));
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "conformance checks failed:\n{}",
+                failures
+                    .iter()
+                    .map(|failure| format!("  - {}", failure))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            ))
+        }
+    }
+
+    /// Run every check against the blocking backend, reporting all failures at once.
+    pub fn run_blocking<B>(&self, backend: &B) -> Result<(), String>
+    where
+        B: BlockingClientBackend,
+        B::Error: Debug,
+    {
+        let backend = Blocking(backend);
+        let mut future = pin!(self.run(&backend));
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut context = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut context) {
+                Poll::Ready(result) => return result,
+                // Sleep until woken, instead of spinning on futures which wait for something.
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+}
+
+/// The [`Waker`] unparking the thread blocked in [`Conformance::run_blocking`].
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+struct Blocking<'a, B>(&'a B);
+
+impl<B: BlockingClientBackend> ClientBackend for Blocking<'_, B> {
+    type Error = B::Error;
+
+    async fn send(&self, request: RawClientRequest) -> Result<RawClientResponse, B::Error> {
+        self.0.send(request)
+    }
+}
+
+fn request(method: HttpMethod, url: String) -> RawClientRequest {
+    RawClientRequest {
+        method,
+        url,
+        headers: Vec::new(),
+        body: None,
+    }
+}
+
+async fn send<B>(backend: &B, request: RawClientRequest) -> Result<RawClientResponse, String>
+where
+    B: ClientBackend,
+    B::Error: Debug,
+{
+    backend
+        .send(request)
+        .await
+        .map_err(|e| format!("backend error: {:?}", e))
+}
+
+async fn inspect<B>(backend: &B, request: RawClientRequest) -> Result<Value, String>
+where
+    B: ClientBackend,
+    B::Error: Debug,
+{
+    let response = send(backend, request).await?;
+    serde_json::from_slice(&response.body).map_err(|e| format!("invalid inspection: {}", e))
+}
+
+fn expect_eq<T: Debug + PartialEq>(what: &str, actual: &T, expected: &T) -> Result<(), String> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(format!(
+            "expected {} to be {:?}, got {:?}",
+            what, expected, actual
+        ))
+    }
+}
+
+fn status_body(code: u16) -> Vec<u8> {
+    if code == 204 {
+        Vec::new()
+    } else {
+        format!("status {}", code).into_bytes()
+    }
+}
+
+fn route(request: &LoopbackRequest) -> LoopbackResponse {
+    let response = |status: u16, headers: Vec<(&str, &str)>, body: Vec<u8>| LoopbackResponse {
+        status,
+        headers: headers
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect(),
+        body,
+    };
+    match request.path.as_str() {
+        "/inspect" => response(
+            200,
+            vec![("Content-Type", "application/json")],
+            json!({
+                "method": request.method,
+                "path": request.path,
+                "headers": request.headers,
+                "body": request.body,
+            })
+            .to_string()
+            .into_bytes(),
+        ),
+        "/echo" => response(
+            200,
+            vec![("Content-Type", "application/octet-stream")],
+            request.body.clone(),
+        ),
+        "/headers" => response(
+            200,
+            vec![
+                ("X-Custom", "custom"),
+                ("Set-Cookie", "a=1"),
+                ("Set-Cookie", "b=2"),
+            ],
+            Vec::new(),
+        ),
+        "/redirect" => response(302, vec![("Location", "/status/200")], Vec::new()),
+        path => match path
+            .strip_prefix("/status/")
+            .and_then(|code| code.parse::<u16>().ok())
+        {
+            Some(code) => response(code, vec![], status_body(code)),
+            None => response(404, vec![], b"not found".to_vec()),
+        },
+    }
+}
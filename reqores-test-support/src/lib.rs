@@ -0,0 +1,8 @@
+//! Test utilities shared by reqores implementations.
+//!
+//! - [`conformance`] - the suite checking that a [`ClientBackend`](`reqores::ClientBackend`) behaves like the others
+//! - [`stub`] - the in-process HTTP server answering with canned [`ServerResponse`](`reqores::ServerResponse`)s
+
+pub mod conformance;
+mod loopback;
+pub mod stub;
@@ -0,0 +1,186 @@
+use std::{
+    io::{self, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::JoinHandle,
+    time::Duration,
+};
+
+use reqores::{
+    wire::{WireError, WireLimits, WireRequest},
+    HttpMethod, HttpStatusCode, ServerRequest,
+};
+
+/// The request received by [`LoopbackServer`].
+pub(crate) struct LoopbackRequest {
+    pub(crate) method: String,
+    pub(crate) path: String,
+    pub(crate) url: String,
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) body: Vec<u8>,
+}
+
+/// The response sent by [`LoopbackServer`].
+pub(crate) struct LoopbackResponse {
+    pub(crate) status: u16,
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) body: Vec<u8>,
+}
+
+type Handler = dyn Fn(&LoopbackRequest) -> LoopbackResponse + Send + Sync;
+
+/// The HTTP/1.1 server listening on 127.0.0.1 with an ephemeral port, closing every connection after a response.
+pub(crate) struct LoopbackServer {
+    addr: SocketAddr,
+    shutdown: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl LoopbackServer {
+    pub(crate) fn start(
+        handler: impl Fn(&LoopbackRequest) -> LoopbackResponse + Send + Sync + 'static,
+    ) -> io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let handler: Arc<Handler> = Arc::new(handler);
+
+        let thread = {
+            let shutdown = shutdown.clone();
+            std::thread::spawn(move || {
+                for stream in listener.incoming() {
+                    if shutdown.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    if let Ok(stream) = stream {
+                        let handler = handler.clone();
+                        std::thread::spawn(move || {
+                            let _ = serve(stream, &*handler);
+                        });
+                    }
+                }
+            })
+        };
+
+        Ok(LoopbackServer {
+            addr,
+            shutdown,
+            thread: Some(thread),
+        })
+    }
+
+    pub(crate) fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    pub(crate) fn url(&self, path: &str) -> String {
+        format!("http://{}{}", self.addr, path)
+    }
+}
+
+impl Drop for LoopbackServer {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        let _ = TcpStream::connect(self.addr);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn serve(mut stream: TcpStream, handler: &Handler) -> io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(5)))?;
+    let local_addr = stream.local_addr()?;
+
+    let wire_request = match read_request(&mut stream)? {
+        Ok(wire_request) => wire_request,
+        Err(e) => {
+            let status = e.status().unwrap_or(HttpStatusCode::BadRequest);
+            return write_response(
+                &mut stream,
+                &LoopbackResponse {
+                    status: status.into(),
+                    headers: vec![],
+                    body: vec![],
+                },
+                false,
+            );
+        }
+    };
+    let request = LoopbackRequest {
+        method: wire_request.method().to_string(),
+        path: wire_request.target().to_string(),
+        url: format!("http://{}{}", local_addr, wire_request.target()),
+        headers: wire_request.headers().to_vec(),
+        body: wire_request.body().to_vec(),
+    };
+    let response = handler(&request);
+    write_response(
+        &mut stream,
+        &response,
+        wire_request.method() != HttpMethod::Head,
+    )
+}
+
+/// Read the request with [`WireRequest::parse`], answering "Expect: 100-continue" once the head arrives.
+fn read_request(stream: &mut TcpStream) -> io::Result<Result<WireRequest, WireError>> {
+    let limits = WireLimits::default();
+    let mut buffer = Vec::new();
+    let mut chunk = [0; 16 * 1024];
+    let mut continued = false;
+    loop {
+        match WireRequest::parse(&buffer, &limits) {
+            Ok((request, _)) => return Ok(Ok(request)),
+            Err(WireError::Incomplete) => {}
+            Err(e) => return Ok(Err(e)),
+        }
+        if !continued && expects_continue(&buffer) {
+            stream.write_all(b"HTTP/1.1 100 Continue\r\n\r\n")?;
+            continued = true;
+        }
+        let read = stream.read(&mut chunk)?;
+        if read == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    }
+}
+
+fn expects_continue(buffer: &[u8]) -> bool {
+    let Some(end) = buffer.windows(4).position(|window| window == b"\r\n\r\n") else {
+        return false;
+    };
+    String::from_utf8_lossy(&buffer[..end])
+        .split("\r\n")
+        .filter_map(|line| line.split_once(':'))
+        .any(|(name, value)| {
+            name.eq_ignore_ascii_case("Expect") && value.trim().eq_ignore_ascii_case("100-continue")
+        })
+}
+
+/// Write the response with the reason phrase of the status, left empty for the status unknown to [`HttpStatusCode`].
+fn write_response(
+    stream: &mut TcpStream,
+    response: &LoopbackResponse,
+    with_body: bool,
+) -> io::Result<()> {
+    let reason = HttpStatusCode::try_from(response.status)
+        .map(|status| status.reason_phrase())
+        .unwrap_or_default();
+    let mut head = format!("HTTP/1.1 {} {}\r\n", response.status, reason);
+    for (name, value) in &response.headers {
+        head.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    head.push_str(&format!(
+        "Content-Length: {}\r\nConnection: close\r\n\r\n",
+        response.body.len()
+    ));
+    stream.write_all(head.as_bytes())?;
+    if with_body {
+        stream.write_all(&response.body)?;
+    }
+    stream.flush()
+}
@@ -0,0 +1,170 @@
+//! The stub HTTP server answering with canned [`ServerResponse`]s.
+//!
+//! ```rust
+//! use reqores::{HttpMethod, ServerResponseBuilder};
+//! use reqores_test_support::stub::StubServerBuilder;
+//!
+//! let server = StubServerBuilder::new()
+//!     .with_route(HttpMethod::Get, "/hello", ServerResponseBuilder::new().body_str("Hello"))
+//!     .start()
+//!     .unwrap();
+//!
+//! let url = server.url("/hello");
+//! // Send requests to `url` with the client under test, then inspect what was received.
+//! assert!(server.requests().is_empty());
+//! ```
+
+use std::{
+    io,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
+use reqores::{
+    HttpMethod, HttpStatusCode, ServerResponse, ServerResponseBuilder, TestServerRequest,
+};
+
+use crate::loopback::{LoopbackRequest, LoopbackResponse, LoopbackServer};
+
+type Handler = Box<dyn Fn(&TestServerRequest) -> ServerResponse + Send + Sync>;
+
+struct Route {
+    method: HttpMethod,
+    path: String,
+    handler: Handler,
+}
+
+/// The builder of [`StubServer`].
+pub struct StubServerBuilder {
+    routes: Vec<Route>,
+    fallback: ServerResponse,
+}
+
+impl Default for StubServerBuilder {
+    fn default() -> Self {
+        StubServerBuilder {
+            routes: Vec::new(),
+            fallback: ServerResponseBuilder::new()
+                .with_status(HttpStatusCode::Notfound)
+                .end(),
+        }
+    }
+}
+
+impl StubServerBuilder {
+    /// Create a new [`StubServerBuilder`] without any route, answering 404 Not Found.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Answer the requests for the method and the path with the response.
+    ///
+    /// The path is compared without the query string.
+    pub fn with_route(
+        self,
+        method: HttpMethod,
+        path: impl Into<String>,
+        response: ServerResponse,
+    ) -> Self {
+        self.with_handler(method, path, move |_| response.clone())
+    }
+
+    /// Answer the requests for the method and the path with the handler.
+    ///
+    /// The path is compared without the query string.
+    pub fn with_handler(
+        mut self,
+        method: HttpMethod,
+        path: impl Into<String>,
+        handler: impl Fn(&TestServerRequest) -> ServerResponse + Send + Sync + 'static,
+    ) -> Self {
+        self.routes.push(Route {
+            method,
+            path: path.into(),
+            handler: Box::new(handler),
+        });
+        self
+    }
+
+    /// Answer the requests no route matched with the response.
+    pub fn with_fallback(mut self, response: ServerResponse) -> Self {
+        self.fallback = response;
+        self
+    }
+
+    /// Start listening on 127.0.0.1 with an ephemeral port.
+    pub fn start(self) -> io::Result<StubServer> {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let server = {
+            let requests = requests.clone();
+            LoopbackServer::start(move |request| {
+                let response = match request.method.parse::<HttpMethod>() {
+                    Ok(method) => {
+                        let path = request.path.split('?').next().unwrap_or_default();
+                        let request = test_request(method, request);
+                        requests.lock().unwrap().push(request.clone());
+                        self.respond(path, &request)
+                    }
+                    Err(_) => ServerResponseBuilder::new()
+                        .with_status(HttpStatusCode::NotImplemented)
+                        .end(),
+                };
+                loopback_response(response)
+            })?
+        };
+        Ok(StubServer { server, requests })
+    }
+
+    fn respond(&self, path: &str, request: &TestServerRequest) -> ServerResponse {
+        self.routes
+            .iter()
+            .find(|route| route.method == request.method() && route.path == path)
+            .map(|route| (route.handler)(request))
+            .unwrap_or_else(|| self.fallback.clone())
+    }
+}
+
+/// The in-process HTTP/1.1 server for integration tests, shutting down on drop.
+///
+/// Every request received is recorded as [`TestServerRequest`],
+/// so the same handlers can be exercised both in memory and over real sockets.
+pub struct StubServer {
+    server: LoopbackServer,
+    requests: Arc<Mutex<Vec<TestServerRequest>>>,
+}
+
+impl StubServer {
+    /// The address the server is listening on.
+    pub fn addr(&self) -> SocketAddr {
+        self.server.addr()
+    }
+
+    /// The URL for the path on the server.
+    pub fn url(&self, path: &str) -> String {
+        self.server.url(path)
+    }
+
+    /// The requests received so far, in the order of arrival.
+    pub fn requests(&self) -> Vec<TestServerRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn test_request(method: HttpMethod, request: &LoopbackRequest) -> TestServerRequest {
+    request
+        .headers
+        .iter()
+        .fold(
+            TestServerRequest::new(method, &request.url),
+            |test_request, (name, value)| test_request.with_header(name, value),
+        )
+        .with_body(request.body.clone())
+}
+
+fn loopback_response(response: ServerResponse) -> LoopbackResponse {
+    LoopbackResponse {
+        status: u16::from(response.status.unwrap_or(HttpStatusCode::Ok)),
+        headers: response.headers,
+        body: response.body.unwrap_or_default(),
+    }
+}
@@ -0,0 +1,123 @@
+use std::{
+    io::{Read, Write},
+    net::{SocketAddr, TcpStream},
+};
+
+use reqores::{HttpMethod, HttpStatusCode, ServerRequest, ServerResponseBuilder};
+use reqores_test_support::stub::StubServerBuilder;
+use serde_json::{json, Value};
+
+fn send(addr: SocketAddr, request: &str) -> String {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream.write_all(request.as_bytes()).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+#[test]
+fn route_answers_with_canned_response() {
+    let server = StubServerBuilder::new()
+        .with_route(
+            HttpMethod::Get,
+            "/hello",
+            ServerResponseBuilder::new()
+                .with_status(HttpStatusCode::Created)
+                .with_header("X-Stub".to_string(), "yes".to_string())
+                .body_str("Hello"),
+        )
+        .start()
+        .unwrap();
+
+    let response = send(
+        server.addr(),
+        "GET /hello?name=stub HTTP/1.1\r\nHost: stub\r\n\r\n",
+    );
+
+    assert!(response.starts_with("HTTP/1.1 201 "), "{}", response);
+    assert!(response.contains("X-Stub: yes\r\n"), "{}", response);
+    assert!(response.ends_with("\r\n\r\nHello"), "{}", response);
+}
+
+#[test]
+fn unmatched_requests_get_fallback() {
+    let server = StubServerBuilder::new()
+        .with_route(
+            HttpMethod::Get,
+            "/hello",
+            ServerResponseBuilder::new().end(),
+        )
+        .start()
+        .unwrap();
+
+    let response = send(server.addr(), "POST /hello HTTP/1.1\r\nHost: stub\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 404 "), "{}", response);
+
+    let server = StubServerBuilder::new()
+        .with_fallback(
+            ServerResponseBuilder::new()
+                .with_status(HttpStatusCode::ServiceUnavailable)
+                .end(),
+        )
+        .start()
+        .unwrap();
+
+    let response = send(server.addr(), "GET / HTTP/1.1\r\nHost: stub\r\n\r\n");
+    assert!(response.starts_with("HTTP/1.1 503 "), "{}", response);
+}
+
+#[test]
+fn requests_are_recorded() {
+    let server = StubServerBuilder::new().start().unwrap();
+
+    send(
+        server.addr(),
+        "PUT /items/1 HTTP/1.1\r\nHost: stub\r\nX-Token: secret\r\nContent-Length: 4\r\n\r\nbody",
+    );
+    send(
+        server.addr(),
+        "DELETE /items/1 HTTP/1.1\r\nHost: stub\r\n\r\n",
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].method(), HttpMethod::Put);
+    assert_eq!(requests[0].url(), server.url("/items/1"));
+    assert_eq!(requests[0].header("x-token").as_deref(), Some("secret"));
+    assert_eq!(requests[0].body(), b"body");
+    assert_eq!(requests[1].method(), HttpMethod::Delete);
+}
+
+#[test]
+fn handler_reads_request() {
+    let server = StubServerBuilder::new()
+        .with_handler(HttpMethod::Post, "/echo", |request| {
+            let body: Value = request.body_json().unwrap();
+            ServerResponseBuilder::new()
+                .body_json(&json!({ "received": body }))
+                .unwrap()
+        })
+        .start()
+        .unwrap();
+
+    let response = send(
+        server.addr(),
+        "POST /echo HTTP/1.1\r\nHost: stub\r\nContent-Length: 7\r\n\r\n{\"a\":1}",
+    );
+
+    assert!(
+        response.ends_with("{\"received\":{\"a\":1}}"),
+        "{}",
+        response
+    );
+}
+
+#[test]
+fn server_shuts_down_on_drop() {
+    let server = StubServerBuilder::new().start().unwrap();
+    let addr = server.addr();
+
+    drop(server);
+
+    assert!(TcpStream::connect(addr).is_err());
+}
@@ -1,43 +1,66 @@
-use reqores::{ClientRequest, HttpMethod};
-use worker::{wasm_bindgen::JsValue, Fetch, Headers, Method, Request, RequestInit};
+use reqores::{ClientBackend, ClientRequest, RawClientRequest, RawClientResponse};
+use worker::Fetch;
 
-use super::client_response::CfWorkerClientResponse;
+use super::{
+    client_response::CfWorkerClientResponse,
+    request::{build_request, call_with},
+    CfFetchOptions,
+};
 
-pub struct CfWorkerClient;
+/// The client sending requests to the public internet with the global `fetch`.
+#[derive(Default)]
+pub struct CfWorkerClient {
+    options: CfFetchOptions,
+}
+
+impl CfWorkerClient {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Apply the options to every request sent by the client.
+    pub fn with_fetch_options(mut self, options: CfFetchOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
 
 impl CfWorkerClient {
     pub async fn call<Req: ClientRequest>(
         &self,
         client_request: Req,
     ) -> Result<Req::Response, worker::Error> {
-        let mut headers = Headers::new();
-        for (k, v) in client_request.headers() {
-            headers.set(&k, &v)?;
-        }
-
-        let mut request_init = RequestInit::new();
-        request_init
-            .with_method(match client_request.method() {
-                HttpMethod::Get => Method::Get,
-                HttpMethod::Post => Method::Post,
-                HttpMethod::Put => Method::Put,
-                HttpMethod::Delete => Method::Delete,
-                HttpMethod::Patch => Method::Patch,
-            })
-            .with_headers(headers)
-            .with_body(client_request.body().map(|s| JsValue::from_str(&s)));
-
-        let request = Fetch::Request(Request::new_with_init(
-            &client_request.url(),
-            &request_init,
-        )?);
-        let response = request.send().await?;
-        let client_response = CfWorkerClientResponse::new(response).await?;
-
-        let result = client_request
-            .deserialize(&client_response)
-            .map_err(worker::Error::RustError)?;
-
-        Ok(result)
+        self.call_with_options(client_request, &self.options).await
     }
+
+    /// Call the request with the options instead of the ones of the client.
+    pub async fn call_with_options<Req: ClientRequest>(
+        &self,
+        client_request: Req,
+        options: &CfFetchOptions,
+    ) -> Result<Req::Response, worker::Error> {
+        call_with(client_request, |raw_request| {
+            send_with_options(raw_request, options)
+        })
+        .await
+    }
+}
+
+impl ClientBackend for CfWorkerClient {
+    type Error = worker::Error;
+
+    async fn send(&self, raw_request: RawClientRequest) -> worker::Result<RawClientResponse> {
+        send_with_options(raw_request, &self.options).await
+    }
+}
+
+async fn send_with_options(
+    raw_request: RawClientRequest,
+    options: &CfFetchOptions,
+) -> worker::Result<RawClientResponse> {
+    let request = Fetch::Request(build_request(raw_request, options)?);
+    let response = request.send().await?;
+    let client_response = CfWorkerClientResponse::new(response).await?;
+
+    Ok(client_response.into_raw())
 }
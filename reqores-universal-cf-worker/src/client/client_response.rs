@@ -1,32 +1,37 @@
-use reqores::{ClientResponse, HttpStatusCode};
+use reqores::{ClientResponse, HttpStatusCode, RawClientResponse};
 use worker::Response;
 
+use crate::convert::client_response;
+
 pub struct CfWorkerClientResponse {
-    body: Vec<u8>,
+    raw: RawClientResponse,
     response: Response,
-    status: HttpStatusCode,
 }
 
 impl CfWorkerClientResponse {
     pub async fn new(mut response: Response) -> worker::Result<Self> {
         let body = response.bytes().await?;
-        let status = HttpStatusCode::try_from(response.status_code())
-            .map_err(|e| worker::Error::RustError(e.to_string()))?;
-        Ok(Self {
+        let raw = client_response(
+            response.status_code(),
+            response.headers().entries().collect(),
             body,
-            response,
-            status,
-        })
+        )
+        .map_err(|e| worker::Error::RustError(e.to_string()))?;
+        Ok(Self { raw, response })
+    }
+
+    pub fn into_raw(self) -> RawClientResponse {
+        self.raw
     }
 }
 
 impl ClientResponse for CfWorkerClientResponse {
     fn body(&self) -> &[u8] {
-        &self.body
+        &self.raw.body
     }
 
     fn status(&self) -> HttpStatusCode {
-        self.status.clone()
+        self.raw.status.clone()
     }
 
     fn header(&self, key: &str) -> Option<String> {
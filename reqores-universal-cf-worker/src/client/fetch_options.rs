@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+
+use js_sys::{global, Array, Function, Object, Reflect};
+use wasm_bindgen::{JsCast, JsValue};
+use worker::{worker_sys, Request, RequestInit, RequestRedirect};
+
+/// The Cloudflare-specific options applied to outgoing requests, like cache controls.
+///
+/// Every option is unset by default, leaving the decision to Cloudflare.
+/// Other backends never see these, so requests stay portable.
+///
+/// The options belong to the client rather than to each [`reqores::ClientRequest`]:
+/// set them with [`CfWorkerClient::with_fetch_options`](super::CfWorkerClient::with_fetch_options)
+/// or per call with [`CfWorkerClient::call_with_options`](super::CfWorkerClient::call_with_options).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CfFetchOptions {
+    cache_ttl: Option<i32>,
+    cache_ttl_by_status: HashMap<String, i32>,
+    cache_everything: Option<bool>,
+    cache_key: Option<String>,
+    resolve_override: Option<String>,
+    image: Option<CfImageResize>,
+    redirect: Option<CfRedirect>,
+}
+
+/// How redirects of outgoing requests are handled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CfRedirect {
+    /// Follow redirects, the default of Cloudflare.
+    Follow,
+    /// Return the redirect response itself.
+    Manual,
+    /// Fail the request on redirect.
+    Error,
+}
+
+/// How the image fetched is resized by Cloudflare Image Resizing.
+///
+/// Every option is unset by default, keeping the image as is.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CfImageResize {
+    width: Option<u32>,
+    height: Option<u32>,
+    fit: Option<CfImageFit>,
+    quality: Option<u8>,
+    format: Option<CfImageFormat>,
+}
+
+/// How the image is fitted into the width and height.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CfImageFit {
+    /// Shrink the image to fit in the size, but never enlarge it.
+    ScaleDown,
+    /// Resize the image to fit in the size, keeping the aspect ratio.
+    Contain,
+    /// Resize the image to fill the size, cropping it if necessary.
+    Cover,
+    /// Shrink and crop the image to fill the size, but never enlarge it.
+    Crop,
+    /// Resize the image to fit in the size, filling the rest with the background color.
+    Pad,
+}
+
+/// The format of the resized image.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CfImageFormat {
+    /// AVIF, the smallest but the slowest to encode.
+    Avif,
+    /// WebP.
+    Webp,
+    /// JPEG.
+    Jpeg,
+    /// PNG, lossless.
+    Png,
+    /// The information about the image as JSON, instead of the image.
+    Json,
+}
+
+impl CfFetchOptions {
+    /// Create a new [`CfFetchOptions`] without any option set.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Cache the response for the seconds, where negative values mean not to cache.
+    pub fn with_cache_ttl(mut self, seconds: i32) -> Self {
+        self.cache_ttl = Some(seconds);
+        self
+    }
+
+    /// Cache the responses of the status range, like "200-299" or "404", for the seconds.
+    pub fn with_cache_ttl_by_status(mut self, statuses: impl Into<String>, seconds: i32) -> Self {
+        self.cache_ttl_by_status.insert(statuses.into(), seconds);
+        self
+    }
+
+    /// Cache every content type, not only static assets.
+    pub fn with_cache_everything(mut self, enabled: bool) -> Self {
+        self.cache_everything = Some(enabled);
+        self
+    }
+
+    /// Use the key instead of the URL to store and look up the response in the cache.
+    pub fn with_cache_key(mut self, key: impl Into<String>) -> Self {
+        self.cache_key = Some(key.into());
+        self
+    }
+
+    /// Send the request to the host instead of the one resolved from the URL.
+    pub fn with_resolve_override(mut self, host: impl Into<String>) -> Self {
+        self.resolve_override = Some(host.into());
+        self
+    }
+
+    /// Resize the image fetched, which needs Image Resizing enabled on the zone.
+    pub fn with_image_resize(mut self, image: CfImageResize) -> Self {
+        self.image = Some(image);
+        self
+    }
+
+    /// Set how redirects are handled.
+    pub fn with_redirect(mut self, redirect: CfRedirect) -> Self {
+        self.redirect = Some(redirect);
+        self
+    }
+
+    pub(crate) fn apply(&self, request_init: &mut RequestInit) {
+        if let Some(redirect) = self.redirect {
+            request_init.with_redirect(match redirect {
+                CfRedirect::Follow => RequestRedirect::Follow,
+                CfRedirect::Manual => RequestRedirect::Manual,
+                CfRedirect::Error => RequestRedirect::Error,
+            });
+        }
+    }
+
+    /// Rebuild the request with the `cf` object of the options, if any option goes there.
+    ///
+    /// `CfProperties` of worker 0.0.11 has no field for image resizing,
+    /// so the whole `cf` object is built here and set with `new Request(request, { cf })`.
+    pub(crate) fn apply_cf(&self, request: Request) -> worker::Result<Request> {
+        let cf = Object::new();
+        set(&cf, "cacheTtl", self.cache_ttl)?;
+        if !self.cache_ttl_by_status.is_empty() {
+            let by_status = Object::new();
+            for (statuses, seconds) in &self.cache_ttl_by_status {
+                set(&by_status, statuses, Some(*seconds))?;
+            }
+            set(&cf, "cacheTtlByStatus", Some(by_status))?;
+        }
+        set(&cf, "cacheEverything", self.cache_everything)?;
+        set(&cf, "cacheKey", self.cache_key.as_deref())?;
+        set(&cf, "resolveOverride", self.resolve_override.as_deref())?;
+        let image = self
+            .image
+            .as_ref()
+            .map(CfImageResize::to_object)
+            .transpose()?;
+        set(&cf, "image", image)?;
+        if Object::keys(&cf).length() == 0 {
+            return Ok(request);
+        }
+
+        let init = Object::new();
+        set(&init, "cf", Some(cf))?;
+        let constructor: Function =
+            Reflect::get(&global(), &JsValue::from("Request"))?.dyn_into()?;
+        let request = Reflect::construct(&constructor, &Array::of2(request.inner(), &init))?;
+        Ok(Request::from(
+            request.unchecked_into::<worker_sys::Request>(),
+        ))
+    }
+}
+
+impl CfImageResize {
+    /// Create a new [`CfImageResize`] without any option set.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the maximum width in pixels.
+    pub fn with_width(mut self, width: u32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Set the maximum height in pixels.
+    pub fn with_height(mut self, height: u32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// Set how the image is fitted into the width and height, defaults to [`CfImageFit::ScaleDown`].
+    pub fn with_fit(mut self, fit: CfImageFit) -> Self {
+        self.fit = Some(fit);
+        self
+    }
+
+    /// Set the quality from 1 to 100, used by lossy formats only.
+    pub fn with_quality(mut self, quality: u8) -> Self {
+        self.quality = Some(quality);
+        self
+    }
+
+    /// Set the format, defaults to the original one or the best one the client accepts.
+    pub fn with_format(mut self, format: CfImageFormat) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    fn to_object(&self) -> worker::Result<Object> {
+        let image = Object::new();
+        set(&image, "width", self.width)?;
+        set(&image, "height", self.height)?;
+        set(
+            &image,
+            "fit",
+            self.fit.map(|fit| match fit {
+                CfImageFit::ScaleDown => "scale-down",
+                CfImageFit::Contain => "contain",
+                CfImageFit::Cover => "cover",
+                CfImageFit::Crop => "crop",
+                CfImageFit::Pad => "pad",
+            }),
+        )?;
+        set(&image, "quality", self.quality)?;
+        set(
+            &image,
+            "format",
+            self.format.map(|format| match format {
+                CfImageFormat::Avif => "avif",
+                CfImageFormat::Webp => "webp",
+                CfImageFormat::Jpeg => "jpeg",
+                CfImageFormat::Png => "png",
+                CfImageFormat::Json => "json",
+            }),
+        )?;
+        Ok(image)
+    }
+}
+
+/// Set the property of the object unless the value is [`None`].
+fn set<T: Into<JsValue>>(object: &Object, key: &str, value: Option<T>) -> worker::Result<()> {
+    if let Some(value) = value {
+        Reflect::set(object, &JsValue::from(key), &value.into())?;
+    }
+    Ok(())
+}
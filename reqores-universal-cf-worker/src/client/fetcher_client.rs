@@ -0,0 +1,80 @@
+use reqores::{ClientBackend, ClientRequest, RawClientRequest, RawClientResponse};
+use worker::{Env, Fetcher};
+
+use super::{
+    client_response::CfWorkerClientResponse,
+    request::{build_request, call_with},
+    CfFetchOptions,
+};
+
+/// The client sending requests through the service binding or any other [`Fetcher`],
+/// instead of the public internet.
+///
+/// The host of the URL is up to the bound worker, since the request never leaves Cloudflare.
+pub struct CfFetcherClient {
+    fetcher: Fetcher,
+    options: CfFetchOptions,
+}
+
+impl CfFetcherClient {
+    pub fn new(fetcher: Fetcher) -> Self {
+        CfFetcherClient {
+            fetcher,
+            options: Default::default(),
+        }
+    }
+
+    /// Create a new [`CfFetcherClient`] with the service binding named `binding` in the [`Env`].
+    pub fn from_env(env: &Env, binding: &str) -> worker::Result<Self> {
+        Ok(CfFetcherClient::new(env.service(binding)?))
+    }
+
+    /// Apply the options to every request sent by the client.
+    pub fn with_fetch_options(mut self, options: CfFetchOptions) -> Self {
+        self.options = options;
+        self
+    }
+}
+
+impl CfFetcherClient {
+    pub async fn call<Req: ClientRequest>(
+        &self,
+        client_request: Req,
+    ) -> Result<Req::Response, worker::Error> {
+        self.call_with_options(client_request, &self.options).await
+    }
+
+    /// Call the request with the options instead of the ones of the client.
+    pub async fn call_with_options<Req: ClientRequest>(
+        &self,
+        client_request: Req,
+        options: &CfFetchOptions,
+    ) -> Result<Req::Response, worker::Error> {
+        call_with(client_request, |raw_request| {
+            self.send_with_options(raw_request, options)
+        })
+        .await
+    }
+
+    async fn send_with_options(
+        &self,
+        raw_request: RawClientRequest,
+        options: &CfFetchOptions,
+    ) -> worker::Result<RawClientResponse> {
+        let response = self
+            .fetcher
+            .fetch_request(build_request(raw_request, options)?)
+            .await?;
+        let client_response = CfWorkerClientResponse::new(response).await?;
+
+        Ok(client_response.into_raw())
+    }
+}
+
+impl ClientBackend for CfFetcherClient {
+    type Error = worker::Error;
+
+    async fn send(&self, raw_request: RawClientRequest) -> worker::Result<RawClientResponse> {
+        self.send_with_options(raw_request, &self.options).await
+    }
+}
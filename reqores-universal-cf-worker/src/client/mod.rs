@@ -1,4 +1,9 @@
 pub use api_client::CfWorkerClient;
+pub use fetch_options::{CfFetchOptions, CfImageFit, CfImageFormat, CfImageResize, CfRedirect};
+pub use fetcher_client::CfFetcherClient;
 
 mod api_client;
 mod client_response;
+mod fetch_options;
+mod fetcher_client;
+mod request;
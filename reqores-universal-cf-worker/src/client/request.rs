@@ -0,0 +1,58 @@
+use std::future::Future;
+
+use reqores::{ClientRequest, HttpMethod, RawClientRequest, RawClientResponse};
+use worker::{js_sys::Uint8Array, Headers, Method, Request, RequestInit};
+
+use super::CfFetchOptions;
+
+/// Build the [`Request`] both [`super::CfWorkerClient`] and [`super::CfFetcherClient`] send.
+pub(crate) fn build_request(
+    raw_request: RawClientRequest,
+    options: &CfFetchOptions,
+) -> worker::Result<Request> {
+    let mut headers = Headers::new();
+    for (k, v) in &raw_request.headers {
+        headers.append(k, v)?;
+    }
+
+    let method = match raw_request.method {
+        HttpMethod::Get => Method::Get,
+        HttpMethod::Post => Method::Post,
+        HttpMethod::Put => Method::Put,
+        HttpMethod::Delete => Method::Delete,
+        HttpMethod::Patch => Method::Patch,
+        HttpMethod::Head => Method::Head,
+        HttpMethod::Options => Method::Options,
+        HttpMethod::Connect => Method::Connect,
+        HttpMethod::Trace => Method::Trace,
+    };
+
+    let mut request_init = RequestInit::new();
+    request_init
+        .with_method(method)
+        .with_headers(headers)
+        .with_body(
+            raw_request
+                .body
+                .map(|body| Uint8Array::from(body.as_slice()).into()),
+        );
+    options.apply(&mut request_init);
+
+    options.apply_cf(Request::new_with_init(&raw_request.url, &request_init)?)
+}
+
+/// Send the [`ClientRequest`] with `send` and deserialize the response, shared by the `call`s of both clients.
+pub(crate) async fn call_with<Req, Fut>(
+    client_request: Req,
+    send: impl FnOnce(RawClientRequest) -> Fut,
+) -> worker::Result<Req::Response>
+where
+    Req: ClientRequest,
+    Fut: Future<Output = worker::Result<RawClientResponse>>,
+{
+    let client_response = send(RawClientRequest::new(&client_request)).await?;
+
+    client_request
+        .deserialize(&client_response)
+        .map_err(worker::Error::RustError)
+}
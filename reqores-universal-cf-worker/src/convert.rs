@@ -0,0 +1,148 @@
+//! The target-independent conversions shared by the wasm-only adapters, so they are tested natively.
+
+use std::net::IpAddr;
+
+use reqores::{
+    HeaderMerge, HttpStatusCode, HttpStatusCodeParseError, RawClientResponse, ServerResponse,
+};
+
+/// The plain parts of the `worker::Response` made from [`ServerResponse`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResponseParts {
+    /// The status code, 200 unless the response has one.
+    pub status: u16,
+    /// The headers to append in order, already merged with [`HeaderMerge::by_default`].
+    pub headers: Vec<(String, String)>,
+    /// The body, empty unless the response has one.
+    pub body: Vec<u8>,
+}
+
+/// Split the [`ServerResponse`] into [`ResponseParts`].
+///
+/// The later value of a repeated header overrides the earlier ones, except "Set-Cookie" which is kept for every cookie.
+pub fn response_parts(server_response: ServerResponse) -> ResponseParts {
+    let mut headers: Vec<(String, String)> = Vec::new();
+    for (name, value) in server_response.headers {
+        if HeaderMerge::by_default(&name) == HeaderMerge::Override {
+            headers.retain(|(existing, _)| !existing.eq_ignore_ascii_case(&name));
+        }
+        headers.push((name, value));
+    }
+
+    ResponseParts {
+        status: u16::from(server_response.status.unwrap_or(HttpStatusCode::Ok)),
+        headers,
+        body: server_response.body.unwrap_or_default(),
+    }
+}
+
+/// Assemble the [`RawClientResponse`] from the parts of the `worker::Response` received.
+pub fn client_response(
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+) -> Result<RawClientResponse, HttpStatusCodeParseError> {
+    Ok(RawClientResponse {
+        status: HttpStatusCode::try_from(status)?,
+        headers,
+        body,
+    })
+}
+
+/// The client address from the value of "CF-Connecting-IP".
+pub fn remote_addr(connecting_ip: Option<&str>) -> Option<IpAddr> {
+    connecting_ip?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use reqores::{HttpStatusCode, ServerResponse, ServerResponseBuilder};
+
+    use super::{client_response, remote_addr, response_parts};
+
+    #[test]
+    fn missing_status_defaults_to_ok() {
+        let parts = response_parts(ServerResponse::default());
+
+        assert_eq!(parts.status, 200);
+        assert!(parts.headers.is_empty());
+        assert!(parts.body.is_empty());
+    }
+
+    #[test]
+    fn status_and_body_are_kept() {
+        let parts = response_parts(
+            ServerResponseBuilder::new()
+                .with_status(HttpStatusCode::Notfound)
+                .body(vec![0, 159, 146, 150]),
+        );
+
+        assert_eq!(parts.status, 404);
+        assert_eq!(parts.body, [0, 159, 146, 150]);
+    }
+
+    #[test]
+    fn repeated_headers_are_overridden() {
+        let parts = response_parts(
+            ServerResponseBuilder::new()
+                .with_header("X-Test".to_string(), "1".to_string())
+                .with_header("Content-Type".to_string(), "text/plain".to_string())
+                .with_header("x-test".to_string(), "2".to_string())
+                .end(),
+        );
+
+        assert_eq!(
+            parts.headers,
+            [
+                ("Content-Type".to_string(), "text/plain".to_string()),
+                ("x-test".to_string(), "2".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn every_set_cookie_is_appended() {
+        let parts = response_parts(
+            ServerResponseBuilder::new()
+                .with_header("Set-Cookie".to_string(), "a=1".to_string())
+                .with_header("set-cookie".to_string(), "b=2".to_string())
+                .end(),
+        );
+
+        assert_eq!(
+            parts.headers,
+            [
+                ("Set-Cookie".to_string(), "a=1".to_string()),
+                ("set-cookie".to_string(), "b=2".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn client_response_checks_status() {
+        let response = client_response(
+            201,
+            vec![("content-type".to_string(), "application/json".to_string())],
+            b"{}".to_vec(),
+        )
+        .unwrap();
+        assert_eq!(response.status, HttpStatusCode::Created);
+        assert_eq!(response.body, b"{}");
+
+        assert!(client_response(599, Vec::new(), Vec::new()).is_err());
+    }
+
+    #[test]
+    fn remote_addr_is_parsed_from_connecting_ip() {
+        assert_eq!(
+            remote_addr(Some("203.0.113.7")),
+            Some("203.0.113.7".parse().unwrap())
+        );
+        assert_eq!(
+            remote_addr(Some("2001:db8::1")),
+            Some("2001:db8::1".parse().unwrap())
+        );
+        assert_eq!(remote_addr(Some("not an address")), None);
+        assert_eq!(remote_addr(None), None);
+    }
+}
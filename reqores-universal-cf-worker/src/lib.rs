@@ -1,4 +1,13 @@
-#[cfg(feature = "client")]
+//! reqores implementation for Cloudflare Workers.
+//!
+//! `client` and `server` are available on wasm32 only, where `worker` functions.
+
+#[cfg(all(feature = "client", target_arch = "wasm32"))]
 pub mod client;
-#[cfg(feature = "server")]
+#[cfg_attr(
+    not(all(feature = "client", feature = "server", target_arch = "wasm32")),
+    allow(dead_code)
+)]
+mod convert;
+#[cfg(all(feature = "server", target_arch = "wasm32"))]
 pub mod server;
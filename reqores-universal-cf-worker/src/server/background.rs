@@ -0,0 +1,34 @@
+use std::{fmt::Display, future::Future};
+
+use worker::{console_error, Context};
+
+/// Run the work after the response is sent, keeping the worker alive until it is done.
+///
+/// The handler can answer right away, like webhooks should, and call other APIs in the background.
+/// The work failing is logged to the console, since nobody else would see the error.
+///
+/// ```rust,ignore
+/// use reqores::ServerResponseBuilder;
+/// use reqores_universal_cf_worker::{client::CfWorkerClient, server::{serve, wait_until}};
+///
+/// serve(request, env, ctx, |request, _env, ctx| async move {
+///     let update = request.body_json::<Update>()?;
+///     wait_until(&ctx, async move {
+///         CfWorkerClient::new().call(SendMessage::reply_to(&update)).await?;
+///         Ok::<_, worker::Error>(())
+///     });
+///     Ok::<_, worker::Error>(ServerResponseBuilder::new().end())
+/// })
+/// .await
+/// ```
+pub fn wait_until<F, E>(ctx: &Context, work: F)
+where
+    F: Future<Output = Result<(), E>> + 'static,
+    E: Display,
+{
+    ctx.wait_until(async move {
+        if let Err(e) = work.await {
+            console_error!("reqores: the background work failed: {}", e);
+        }
+    });
+}
@@ -1,5 +1,9 @@
+pub use background::wait_until;
+pub use serve::serve;
 pub use server_request::{decode_request, CfWorkerServerRequest};
 pub use server_response::encode_response;
 
+mod background;
+mod serve;
 mod server_request;
 mod server_response;
@@ -0,0 +1,108 @@
+use std::{
+    fmt::Display,
+    future::Future,
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    sync::Once,
+    task::{Context as TaskContext, Poll},
+};
+
+use reqores::{HttpStatusCode, ServerResponse};
+use worker::{console_error, Context, Env, Request, Response};
+
+use super::{decode_request, encode_response, CfWorkerServerRequest};
+
+/// Answer the fetch event with the handler, from decoding the request to encoding the response.
+///
+/// [`Env`] and [`Context`] are passed through to the handler,
+/// so that it can keep working after responding with [`wait_until`](super::wait_until).
+/// Nothing escapes as an error: failing to decode the request, the handler returning an error,
+/// and failing to encode the response are all logged and answered with 500 Internal Server Error.
+///
+/// Panics are logged too, but wasm32-unknown-unknown aborts on panic,
+/// so the runtime answers instead of this function on that target.
+///
+/// ```rust,ignore
+/// use reqores::ServerResponseBuilder;
+/// use worker::{event, Context, Env, Request, Response};
+///
+/// #[event(fetch)]
+/// pub async fn main(request: Request, env: Env, ctx: Context) -> worker::Result<Response> {
+///     reqores_universal_cf_worker::server::serve(request, env, ctx, |request, env, _ctx| async move {
+///         let secret = env.secret("WEBHOOK_SECRET")?.to_string();
+///         // ... route and handle the request ...
+///         Ok::<_, worker::Error>(ServerResponseBuilder::new().end())
+///     })
+///     .await
+/// }
+/// ```
+pub async fn serve<H, Fut, E>(
+    request: Request,
+    env: Env,
+    ctx: Context,
+    handler: H,
+) -> worker::Result<Response>
+where
+    H: FnOnce(CfWorkerServerRequest, Env, Context) -> Fut,
+    Fut: Future<Output = Result<ServerResponse, E>>,
+    E: Display,
+{
+    log_panics();
+
+    let request = match decode_request(request).await {
+        Ok(request) => request,
+        Err(e) => {
+            console_error!("reqores: failed to decode the request: {}", e);
+            return internal_server_error();
+        }
+    };
+
+    let server_response = match CatchUnwind(Box::pin(handler(request, env, ctx))).await {
+        Ok(Ok(server_response)) => server_response,
+        Ok(Err(e)) => {
+            console_error!("reqores: the handler failed: {}", e);
+            return internal_server_error();
+        }
+        Err(_) => return internal_server_error(),
+    };
+
+    match encode_response(server_response) {
+        Ok(response) => Ok(response),
+        Err(e) => {
+            console_error!("reqores: failed to encode the response: {}", e);
+            internal_server_error()
+        }
+    }
+}
+
+fn internal_server_error() -> worker::Result<Response> {
+    let status = HttpStatusCode::InternalServerError;
+    Response::error(status.reason_phrase(), u16::from(status))
+}
+
+/// Log panics to the console, where the message would be lost otherwise.
+fn log_panics() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            console_error!("reqores: the handler panicked: {}", info);
+            default_hook(info);
+        }));
+    });
+}
+
+/// Poll the future, turning the panic into the error on targets which unwind.
+struct CatchUnwind<F>(Pin<Box<F>>);
+
+impl<F: Future> Future for CatchUnwind<F> {
+    type Output = std::thread::Result<F::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Self::Output> {
+        match panic::catch_unwind(AssertUnwindSafe(|| self.0.as_mut().poll(cx))) {
+            Ok(Poll::Pending) => Poll::Pending,
+            Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+            Err(payload) => Poll::Ready(Err(payload)),
+        }
+    }
+}
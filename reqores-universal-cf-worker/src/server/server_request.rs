@@ -1,15 +1,101 @@
-use reqores::ServerRequest;
-use worker::Request;
+use std::net::IpAddr;
+
+use js_sys::{JsString, Reflect};
+use reqores::{HttpMethod, ServerRequest};
+use worker::{Cf, Method, Request};
+
+use crate::convert::remote_addr;
 
 pub struct CfWorkerServerRequest {
     request: Request,
+    url: String,
     body: Vec<u8>,
 }
 
 impl CfWorkerServerRequest {
     pub async fn new(mut request: Request) -> worker::Result<Self> {
+        let url = request.url()?.to_string();
         let body = request.bytes().await?;
-        Ok(Self { request, body })
+        Ok(Self { request, url, body })
+    }
+
+    /// The underlying [`Request`].
+    pub fn request(&self) -> &Request {
+        &self.request
+    }
+
+    /// Every metadata Cloudflare attached to the request.
+    ///
+    /// [`Cf`] does not expose the client TCP RTT and the bot score,
+    /// read them with [`CfWorkerServerRequest::client_tcp_rtt`] and [`CfWorkerServerRequest::bot_score`].
+    pub fn cf(&self) -> &Cf {
+        self.request.cf()
+    }
+
+    /// Read the number at the path of keys from the raw `cf` object of the request.
+    fn cf_number(&self, path: &[&str]) -> Option<u32> {
+        let mut value = Reflect::get(self.request.inner(), &JsString::from("cf").into()).ok()?;
+        for key in path {
+            value = Reflect::get(&value, &JsString::from(*key).into()).ok()?;
+        }
+        value.as_f64().map(|number| number as u32)
+    }
+
+    /// The smoothed TCP round-trip time between the client and Cloudflare in milliseconds.
+    pub fn client_tcp_rtt(&self) -> Option<u32> {
+        self.cf_number(&["clientTcpRtt"])
+    }
+
+    /// The bot score from 1, likely automated, to 99, likely human.
+    ///
+    /// It is only present on zones with Bot Management enabled.
+    pub fn bot_score(&self) -> Option<u32> {
+        self.cf_number(&["botManagement", "score"])
+    }
+
+    /// The two-letter country code of the client, like "KR".
+    pub fn country(&self) -> Option<String> {
+        self.cf().country()
+    }
+
+    /// The IATA airport code of the data center that received the request, like "ICN".
+    pub fn colo(&self) -> String {
+        self.cf().colo()
+    }
+
+    /// The autonomous system number of the client.
+    pub fn asn(&self) -> u32 {
+        self.cf().asn()
+    }
+
+    /// The TLS version of the connection, like "TLSv1.3", or empty over plain HTTP.
+    pub fn tls_version(&self) -> String {
+        self.cf().tls_version()
+    }
+
+    /// The HTTP protocol of the connection, like "HTTP/2".
+    pub fn http_protocol(&self) -> String {
+        self.cf().http_protocol()
+    }
+
+    /// The HTTP method of the request.
+    pub fn method(&self) -> HttpMethod {
+        match self.request.method() {
+            Method::Head => HttpMethod::Head,
+            Method::Get => HttpMethod::Get,
+            Method::Post => HttpMethod::Post,
+            Method::Put => HttpMethod::Put,
+            Method::Patch => HttpMethod::Patch,
+            Method::Delete => HttpMethod::Delete,
+            Method::Options => HttpMethod::Options,
+            Method::Connect => HttpMethod::Connect,
+            Method::Trace => HttpMethod::Trace,
+        }
+    }
+
+    /// The URL of the request.
+    pub fn url(&self) -> String {
+        self.url.clone()
     }
 }
 
@@ -21,6 +107,11 @@ impl ServerRequest for CfWorkerServerRequest {
     fn header(&self, key: &str) -> Option<String> {
         self.request.headers().get(key).ok().flatten()
     }
+
+    /// The client address from "CF-Connecting-IP", which Cloudflare always sets.
+    fn remote_addr(&self) -> Option<IpAddr> {
+        remote_addr(self.header("CF-Connecting-IP").as_deref())
+    }
 }
 
 pub async fn decode_request(request: Request) -> worker::Result<CfWorkerServerRequest> {
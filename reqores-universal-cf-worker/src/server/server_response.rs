@@ -1,14 +1,14 @@
 use reqores::ServerResponse;
 use worker::Response;
 
+use crate::convert::response_parts;
+
 pub fn encode_response(server_response: ServerResponse) -> worker::Result<Response> {
-    let mut response = Response::from_bytes(server_response.body.unwrap_or_default())?;
-    if let Some(code) = server_response.status {
-        response = response.with_status(u16::from(code));
-    }
+    let parts = response_parts(server_response);
+    let mut response = Response::from_bytes(parts.body)?.with_status(parts.status);
 
-    for (name, value) in server_response.headers {
-        response.headers_mut().set(&name, &value)?;
+    for (name, value) in parts.headers {
+        response.headers_mut().append(&name, &value)?;
     }
 
     Ok(response)
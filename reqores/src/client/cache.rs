@@ -0,0 +1,378 @@
+//! The client-side HTTP cache honoring "Cache-Control" and "ETag".
+//!
+//! Wrap any [`ClientBackend`] with [`CachingClient`] to store GET responses and serve them while they are fresh.
+//! Stale responses having validators are revalidated with "If-None-Match" and "If-Modified-Since".
+
+use std::{
+    future::Future,
+    sync::Mutex,
+    time::{Duration, SystemTime},
+};
+
+use crate::{ClientBackend, HttpMethod, HttpStatusCode, RawClientRequest, RawClientResponse};
+
+/// The source of the current time, replaceable for tests or platforms without [`SystemTime::now`].
+pub trait Clock {
+    /// The current time.
+    fn now(&self) -> SystemTime;
+}
+
+/// The [`Clock`] using [`SystemTime::now`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+impl<F: Fn() -> SystemTime> Clock for F {
+    fn now(&self) -> SystemTime {
+        self()
+    }
+}
+
+/// The response stored in [`CacheStorage`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CacheEntry {
+    /// The response to serve.
+    pub response: RawClientResponse,
+    /// The request headers listed in "Vary" and their values when the response was stored.
+    pub vary: Vec<(String, Option<String>)>,
+    /// The time when the response was stored or revalidated.
+    pub stored_at: SystemTime,
+    /// How long the response stays fresh after `stored_at`.
+    pub freshness: Duration,
+}
+
+impl CacheEntry {
+    /// Create a new [`CacheEntry`] if the response is allowed to be stored.
+    ///
+    /// Only 200 OK responses without "Cache-Control: no-store" and "Vary: *" are stored,
+    /// and they need either "max-age" or a validator to be useful.
+    pub fn new(
+        request: &RawClientRequest,
+        response: RawClientResponse,
+        now: SystemTime,
+    ) -> Option<Self> {
+        if response.status != HttpStatusCode::Ok {
+            return None;
+        }
+        let cache_control = CacheControl::from_headers(&response.headers);
+        if cache_control.no_store {
+            return None;
+        }
+        let vary = header(&response.headers, "Vary")
+            .map(|vary| {
+                vary.split(',')
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty())
+                    .map(|name| (name.to_string(), request.header(name).map(String::from)))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        if vary.iter().any(|(name, _)| name == "*") {
+            return None;
+        }
+        let has_validator = header(&response.headers, "ETag").is_some()
+            || header(&response.headers, "Last-Modified").is_some();
+        if cache_control.max_age.is_none() && !has_validator {
+            return None;
+        }
+        Some(CacheEntry {
+            freshness: freshness(&response.headers),
+            response,
+            vary,
+            stored_at: now,
+        })
+    }
+
+    /// Whether the entry can be served without revalidation.
+    pub fn is_fresh(&self, now: SystemTime) -> bool {
+        now.duration_since(self.stored_at)
+            .map(|age| age < self.freshness)
+            .unwrap_or(true)
+    }
+
+    /// Whether the entry was stored for the request with the same headers listed in "Vary".
+    pub fn matches(&self, request: &RawClientRequest) -> bool {
+        self.vary
+            .iter()
+            .all(|(name, value)| request.header(name) == value.as_deref())
+    }
+
+    fn revalidated(mut self, not_modified: RawClientResponse, now: SystemTime) -> Self {
+        for (name, value) in not_modified.headers {
+            self.response
+                .headers
+                .retain(|(existing, _)| !existing.eq_ignore_ascii_case(&name));
+            self.response.headers.push((name, value));
+        }
+        self.freshness = freshness(&self.response.headers);
+        self.stored_at = now;
+        self
+    }
+}
+
+/// The storage for [`CachingClient`].
+///
+/// Entries are looked up by URL, which holds one variant for each value of the request headers listed in "Vary",
+/// so a variant is identified by the URL and its [`CacheEntry::vary`] together.
+/// The methods are asynchronous so that remote storages can be plugged in,
+/// but their futures are not required to be [`Send`], like those of [`ClientBackend`].
+/// Failures of the storage should be treated as cache misses rather than errors.
+pub trait CacheStorage {
+    /// The variants stored with the key, empty if nothing is stored.
+    fn get(&self, key: &str) -> impl Future<Output = Vec<CacheEntry>>;
+
+    /// Store the variants with the key, replacing the previous ones.
+    fn put(&self, key: &str, variants: Vec<CacheEntry>) -> impl Future<Output = ()>;
+
+    /// Remove every variant stored with the key.
+    fn remove(&self, key: &str) -> impl Future<Output = ()>;
+}
+
+/// The in-memory [`CacheStorage`] evicting the least recently used entry when it is full.
+#[derive(Debug)]
+pub struct MemoryCacheStorage {
+    capacity: usize,
+    entries: Mutex<Vec<(String, Vec<CacheEntry>)>>,
+}
+
+impl MemoryCacheStorage {
+    /// Create a new [`MemoryCacheStorage`] holding at most `capacity` entries.
+    pub fn new(capacity: usize) -> Self {
+        MemoryCacheStorage {
+            capacity,
+            entries: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// The number of entries stored, counting every variant.
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(_, variants)| variants.len())
+            .sum()
+    }
+
+    /// Whether nothing is stored.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl CacheStorage for MemoryCacheStorage {
+    async fn get(&self, key: &str) -> Vec<CacheEntry> {
+        let mut entries = self.entries.lock().unwrap();
+        let Some(index) = entries.iter().position(|(existing, _)| existing == key) else {
+            return Vec::new();
+        };
+        let entry = entries.remove(index);
+        let result = entry.1.clone();
+        entries.push(entry);
+        result
+    }
+
+    async fn put(&self, key: &str, variants: Vec<CacheEntry>) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|(existing, _)| existing != key);
+        entries.push((key.to_string(), variants));
+        let mut len: usize = entries.iter().map(|(_, variants)| variants.len()).sum();
+        while len > self.capacity {
+            let (_, oldest) = &mut entries[0];
+            oldest.remove(0);
+            if oldest.is_empty() {
+                entries.remove(0);
+            }
+            len -= 1;
+        }
+    }
+
+    async fn remove(&self, key: &str) {
+        self.entries
+            .lock()
+            .unwrap()
+            .retain(|(existing, _)| existing != key);
+    }
+}
+
+/// The [`ClientBackend`] caching GET responses of the inner backend.
+///
+/// Responses are keyed by URL and the values of the request headers listed in "Vary",
+/// so requests differing in those headers are served from their own entries.
+/// Successful requests with unsafe methods like POST invalidate the entry of their URL,
+/// while safe ones like HEAD leave it.
+pub struct CachingClient<B, S, C = SystemClock> {
+    backend: B,
+    storage: S,
+    clock: C,
+}
+
+impl<B, S> CachingClient<B, S> {
+    /// Create a new [`CachingClient`].
+    pub fn new(backend: B, storage: S) -> Self {
+        CachingClient {
+            backend,
+            storage,
+            clock: SystemClock,
+        }
+    }
+}
+
+impl<B, S, C> CachingClient<B, S, C> {
+    /// Create a new [`CachingClient`] with the clock.
+    pub fn with_clock(backend: B, storage: S, clock: C) -> Self {
+        CachingClient {
+            backend,
+            storage,
+            clock,
+        }
+    }
+
+    /// The inner backend.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// The storage of the cache.
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+}
+
+impl<B: ClientBackend, S: CacheStorage, C: Clock> ClientBackend for CachingClient<B, S, C> {
+    type Error = B::Error;
+
+    async fn send(&self, mut request: RawClientRequest) -> Result<RawClientResponse, Self::Error> {
+        let key = request.url.clone();
+
+        if request.method != HttpMethod::Get {
+            let safe_method = matches!(
+                request.method,
+                HttpMethod::Head | HttpMethod::Options | HttpMethod::Trace
+            );
+            let response = self.backend.send(request).await?;
+            if !safe_method && (200..400).contains(&u16::from(response.status.clone())) {
+                self.storage.remove(&key).await;
+            }
+            return Ok(response);
+        }
+
+        let request_cache_control = CacheControl::from_headers(&request.headers);
+        if request_cache_control.no_store
+            || request.header("If-None-Match").is_some()
+            || request.header("If-Modified-Since").is_some()
+        {
+            return self.backend.send(request).await;
+        }
+
+        let variants = self.storage.get(&key).await;
+        let cached = variants
+            .iter()
+            .find(|entry| entry.matches(&request))
+            .cloned();
+        if let Some(entry) = &cached {
+            if !request_cache_control.no_cache && entry.is_fresh(self.clock.now()) {
+                return Ok(entry.response.clone());
+            }
+            if let Some(etag) = header(&entry.response.headers, "ETag") {
+                request.set_header("If-None-Match".to_string(), etag.to_string());
+            }
+            if let Some(last_modified) = header(&entry.response.headers, "Last-Modified") {
+                request.set_header("If-Modified-Since".to_string(), last_modified.to_string());
+            }
+        }
+
+        let response = self.backend.send(request.clone()).await?;
+        let now = self.clock.now();
+
+        if response.status == HttpStatusCode::NotModified {
+            if let Some(entry) = cached {
+                let entry = entry.revalidated(response, now);
+                let response = entry.response.clone();
+                self.store_variant(&key, variants, &request, Some(entry))
+                    .await;
+                return Ok(response);
+            }
+        }
+
+        let entry = CacheEntry::new(&request, response.clone(), now);
+        self.store_variant(&key, variants, &request, entry).await;
+        Ok(response)
+    }
+}
+
+impl<B, S: CacheStorage, C> CachingClient<B, S, C> {
+    /// Replace the variant matching the request with the entry, removing it if there is no entry.
+    async fn store_variant(
+        &self,
+        key: &str,
+        mut variants: Vec<CacheEntry>,
+        request: &RawClientRequest,
+        entry: Option<CacheEntry>,
+    ) {
+        variants.retain(|existing| !existing.matches(request));
+        variants.extend(entry);
+        if variants.is_empty() {
+            self.storage.remove(key).await;
+        } else {
+            self.storage.put(key, variants).await;
+        }
+    }
+}
+
+#[derive(Default)]
+struct CacheControl {
+    no_store: bool,
+    no_cache: bool,
+    max_age: Option<u64>,
+}
+
+impl CacheControl {
+    fn from_headers(headers: &[(String, String)]) -> Self {
+        let mut cache_control = CacheControl::default();
+        let directives = headers
+            .iter()
+            .filter(|(name, _)| name.eq_ignore_ascii_case("Cache-Control"))
+            .flat_map(|(_, value)| value.split(','));
+        for directive in directives {
+            let (name, value) = match directive.split_once('=') {
+                Some((name, value)) => (name.trim(), Some(value.trim().trim_matches('"'))),
+                None => (directive.trim(), None),
+            };
+            if name.eq_ignore_ascii_case("no-store") {
+                cache_control.no_store = true;
+            } else if name.eq_ignore_ascii_case("no-cache") {
+                cache_control.no_cache = true;
+            } else if name.eq_ignore_ascii_case("max-age") {
+                cache_control.max_age = value.and_then(|value| value.parse().ok());
+            }
+        }
+        cache_control
+    }
+}
+
+fn freshness(headers: &[(String, String)]) -> Duration {
+    let cache_control = CacheControl::from_headers(headers);
+    if cache_control.no_cache {
+        return Duration::ZERO;
+    }
+    let age = header(headers, "Age")
+        .and_then(|age| age.trim().parse().ok())
+        .unwrap_or(0);
+    Duration::from_secs(cache_control.max_age.unwrap_or(0).saturating_sub(age))
+}
+
+fn header<'a>(headers: &'a [(String, String)], key: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(key))
+        .map(|(_, value)| value.as_str())
+}
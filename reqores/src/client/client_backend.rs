@@ -0,0 +1,100 @@
+use std::future::Future;
+
+use super::{ClientRequest, RawClientRequest, RawClientResponse};
+
+/// The backend sending [`RawClientRequest`]s over the network, or anywhere else.
+///
+/// Every [`ClientRequest`] can be called through any backend, so wrappers like caches can be stacked on top of it.
+///
+/// The futures are not required to be [`Send`], so that backends on single-threaded platforms like Cloudflare Workers fit in.
+/// Hence generic wrappers like [`CachingClient`](crate::cache::CachingClient) return futures that cannot be `tokio::spawn`ed
+/// even over a [`Send`] backend; await them in place or spawn them with `tokio::task::spawn_local`.
+pub trait ClientBackend {
+    /// The error type of the backend.
+    type Error;
+
+    /// Send the request and receive the whole response.
+    fn send(
+        &self,
+        request: RawClientRequest,
+    ) -> impl Future<Output = Result<RawClientResponse, Self::Error>>;
+
+    /// Send the [`ClientRequest`] and deserialize the response.
+    fn call<Req: ClientRequest>(
+        &self,
+        client_request: Req,
+    ) -> impl Future<Output = Result<Req::Response, ClientCallError<Self::Error>>> {
+        async move {
+            let response = self
+                .send(RawClientRequest::new(&client_request))
+                .await
+                .map_err(ClientCallError::Backend)?;
+            client_request
+                .deserialize(&response)
+                .map_err(ClientCallError::Deserialize)
+        }
+    }
+}
+
+impl<B: ClientBackend + ?Sized> ClientBackend for &B {
+    type Error = B::Error;
+
+    fn send(
+        &self,
+        request: RawClientRequest,
+    ) -> impl Future<Output = Result<RawClientResponse, Self::Error>> {
+        (**self).send(request)
+    }
+}
+
+/// The synchronous counterpart of [`ClientBackend`], for tools without an async runtime.
+///
+/// The status, header and body semantics are the same as [`ClientBackend`].
+pub trait BlockingClientBackend {
+    /// The error type of the backend.
+    type Error;
+
+    /// Send the request and receive the whole response, blocking the current thread.
+    fn send(&self, request: RawClientRequest) -> Result<RawClientResponse, Self::Error>;
+
+    /// Send the [`ClientRequest`] and deserialize the response, blocking the current thread.
+    fn call<Req: ClientRequest>(
+        &self,
+        client_request: Req,
+    ) -> Result<Req::Response, ClientCallError<Self::Error>> {
+        let response = self
+            .send(RawClientRequest::new(&client_request))
+            .map_err(ClientCallError::Backend)?;
+        client_request
+            .deserialize(&response)
+            .map_err(ClientCallError::Deserialize)
+    }
+}
+
+impl<B: BlockingClientBackend + ?Sized> BlockingClientBackend for &B {
+    type Error = B::Error;
+
+    fn send(&self, request: RawClientRequest) -> Result<RawClientResponse, Self::Error> {
+        (**self).send(request)
+    }
+}
+
+/// The error type for [`ClientBackend::call`] and [`BlockingClientBackend::call`].
+#[derive(Debug)]
+pub enum ClientCallError<E> {
+    /// The backend failed to send the request.
+    Backend(E),
+    /// The response could not be deserialized.
+    Deserialize(String),
+}
+
+impl<E: core::fmt::Debug + core::fmt::Display> std::error::Error for ClientCallError<E> {}
+
+impl<E: core::fmt::Display> core::fmt::Display for ClientCallError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ClientCallError::Backend(e) => write!(f, "Backend Error: {}", e),
+            ClientCallError::Deserialize(e) => write!(f, "Deserialize Error: {}", e),
+        }
+    }
+}
@@ -45,3 +45,43 @@ pub trait ClientRequest {
         serde_json::from_slice(response.body()).map_err(|e| e.to_string())
     }
 }
+
+/// The request made from [`ClientRequest`], ready to be sent by [`ClientBackend`](`crate::ClientBackend`).
+#[derive(Clone, Debug, PartialEq)]
+pub struct RawClientRequest {
+    /// The HTTP method to use.
+    pub method: HttpMethod,
+    /// The URL endpoint.
+    pub url: String,
+    /// The headers to send.
+    pub headers: Vec<(String, String)>,
+    /// The request body to send.
+    pub body: Option<Vec<u8>>,
+}
+
+impl RawClientRequest {
+    /// Create a new [`RawClientRequest`] from the [`ClientRequest`].
+    pub fn new<Req: ClientRequest + ?Sized>(request: &Req) -> Self {
+        RawClientRequest {
+            method: request.method(),
+            url: request.url(),
+            headers: request.headers(),
+            body: request.body().map(String::into_bytes),
+        }
+    }
+
+    /// The header value from the given key.
+    pub fn header(&self, key: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Set the header, replacing every value having the same name.
+    pub fn set_header(&mut self, name: String, value: String) {
+        self.headers
+            .retain(|(existing, _)| !existing.eq_ignore_ascii_case(&name));
+        self.headers.push((name, value));
+    }
+}
@@ -11,3 +11,31 @@ pub trait ClientResponse {
     /// The header value from the given key.
     fn header(&self, key: &str) -> Option<String>;
 }
+
+/// The response received by [`ClientBackend`](`crate::ClientBackend`), holding everything in memory.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RawClientResponse {
+    /// The HTTP Status Code of the response.
+    pub status: HttpStatusCode,
+    /// The headers of the response.
+    pub headers: Vec<(String, String)>,
+    /// The body of the response.
+    pub body: Vec<u8>,
+}
+
+impl ClientResponse for RawClientResponse {
+    fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    fn status(&self) -> HttpStatusCode {
+        self.status.clone()
+    }
+
+    fn header(&self, key: &str) -> Option<String> {
+        self.headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value.clone())
+    }
+}
@@ -0,0 +1,308 @@
+//! The client-side cookie jar for session-based APIs.
+//!
+//! Wrap any [`ClientBackend`] with [`CookieJarClient`] to capture "Set-Cookie" from responses
+//! and send them back in "Cookie" header of later requests.
+//! The [`CookieJar`] is serializable, so it can be persisted between worker invocations.
+//!
+//! Note that public suffixes are not checked, so only talk to the sites you trust.
+
+use std::{sync::Mutex, time::SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cache::{Clock, SystemClock},
+    ClientBackend, RawClientRequest, RawClientResponse,
+};
+
+/// The cookie stored in [`CookieJar`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StoredCookie {
+    /// The name of the cookie.
+    pub name: String,
+    /// The value of the cookie.
+    pub value: String,
+    /// The domain of the cookie, lowercased and without leading dot.
+    pub domain: String,
+    /// Whether the cookie is only sent to the exact domain, which is when "Domain" attribute is absent.
+    pub host_only: bool,
+    /// The path of the cookie.
+    pub path: String,
+    /// The time when the cookie expires, or [`None`] for session cookies.
+    pub expires: Option<SystemTime>,
+    /// Whether the cookie is only sent over https.
+    pub secure: bool,
+}
+
+impl StoredCookie {
+    /// Parse "Set-Cookie" header value received from the URL.
+    ///
+    /// Returns [`None`] if the header is malformed or its "Domain" does not match the URL.
+    pub fn parse(set_cookie: &str, url: &str, now: SystemTime) -> Option<Self> {
+        let url = Url::parse(url)?;
+        let mut parts = set_cookie.split(';');
+        let (name, value) = parts.next()?.split_once('=')?;
+        let name = name.trim();
+        if name.is_empty() {
+            return None;
+        }
+
+        let mut cookie = StoredCookie {
+            name: name.to_string(),
+            value: value.trim().to_string(),
+            domain: url.host.clone(),
+            host_only: true,
+            path: default_path(&url.path),
+            expires: None,
+            secure: false,
+        };
+        let mut max_age = None;
+        for attribute in parts {
+            let (key, value) = match attribute.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => (attribute.trim(), ""),
+            };
+            if key.eq_ignore_ascii_case("Expires") {
+                if let Ok(expires) = httpdate::parse_http_date(value) {
+                    cookie.expires = Some(expires);
+                }
+            } else if key.eq_ignore_ascii_case("Max-Age") {
+                if let Ok(seconds) = value.parse::<i64>() {
+                    max_age = Some(seconds);
+                }
+            } else if key.eq_ignore_ascii_case("Domain") {
+                let domain = value.trim_start_matches('.').to_ascii_lowercase();
+                if !domain.is_empty() {
+                    if !domain_matches(&url.host, &domain) {
+                        return None;
+                    }
+                    cookie.domain = domain;
+                    cookie.host_only = false;
+                }
+            } else if key.eq_ignore_ascii_case("Path") {
+                if value.starts_with('/') {
+                    cookie.path = value.to_string();
+                }
+            } else if key.eq_ignore_ascii_case("Secure") {
+                cookie.secure = true;
+            }
+        }
+        if let Some(seconds) = max_age {
+            // The expiry beyond what SystemTime represents is as good as never expiring.
+            cookie.expires = if seconds <= 0 {
+                Some(SystemTime::UNIX_EPOCH)
+            } else {
+                now.checked_add(std::time::Duration::from_secs(seconds as u64))
+            };
+        }
+        Some(cookie)
+    }
+
+    /// Whether the cookie has expired.
+    pub fn is_expired(&self, now: SystemTime) -> bool {
+        matches!(self.expires, Some(expires) if expires <= now)
+    }
+
+    /// Whether the cookie should be sent to the URL.
+    pub fn matches(&self, url: &str) -> bool {
+        let url = match Url::parse(url) {
+            Some(url) => url,
+            None => return false,
+        };
+        let domain_matched = if self.host_only {
+            url.host == self.domain
+        } else {
+            domain_matches(&url.host, &self.domain)
+        };
+        domain_matched && path_matches(&url.path, &self.path) && (!self.secure || url.secure)
+    }
+}
+
+/// The collection of cookies.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CookieJar {
+    cookies: Vec<StoredCookie>,
+}
+
+impl CookieJar {
+    /// Create a new empty [`CookieJar`].
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The cookies stored.
+    pub fn cookies(&self) -> &[StoredCookie] {
+        &self.cookies
+    }
+
+    /// Store the cookie, replacing the one with the same name, domain and path.
+    ///
+    /// Storing an expired cookie removes the existing one.
+    pub fn store(&mut self, cookie: StoredCookie, now: SystemTime) {
+        self.cookies.retain(|existing| {
+            !(existing.name == cookie.name
+                && existing.domain == cookie.domain
+                && existing.path == cookie.path)
+        });
+        if !cookie.is_expired(now) {
+            self.cookies.push(cookie);
+        }
+    }
+
+    /// Store every "Set-Cookie" from the response headers received from the URL.
+    pub fn store_response(&mut self, url: &str, headers: &[(String, String)], now: SystemTime) {
+        for (name, value) in headers {
+            if name.eq_ignore_ascii_case("Set-Cookie") {
+                if let Some(cookie) = StoredCookie::parse(value, url, now) {
+                    self.store(cookie, now);
+                }
+            }
+        }
+    }
+
+    /// "Cookie" header value for the URL, with longer paths first.
+    pub fn cookie_header(&self, url: &str, now: SystemTime) -> Option<String> {
+        let mut cookies: Vec<_> = self
+            .cookies
+            .iter()
+            .filter(|cookie| !cookie.is_expired(now) && cookie.matches(url))
+            .collect();
+        if cookies.is_empty() {
+            return None;
+        }
+        cookies.sort_by_key(|cookie| std::cmp::Reverse(cookie.path.len()));
+        Some(
+            cookies
+                .iter()
+                .map(|cookie| format!("{}={}", cookie.name, cookie.value))
+                .collect::<Vec<_>>()
+                .join("; "),
+        )
+    }
+
+    /// Remove expired cookies.
+    pub fn remove_expired(&mut self, now: SystemTime) {
+        self.cookies.retain(|cookie| !cookie.is_expired(now));
+    }
+}
+
+/// The [`ClientBackend`] keeping cookies between requests of the inner backend.
+///
+/// "Cookie" header is only injected into requests without one.
+pub struct CookieJarClient<B, C = SystemClock> {
+    backend: B,
+    jar: Mutex<CookieJar>,
+    clock: C,
+}
+
+impl<B> CookieJarClient<B> {
+    /// Create a new [`CookieJarClient`] with an empty jar.
+    pub fn new(backend: B) -> Self {
+        CookieJarClient::with_jar(backend, CookieJar::new())
+    }
+
+    /// Create a new [`CookieJarClient`] with the jar restored from somewhere.
+    pub fn with_jar(backend: B, jar: CookieJar) -> Self {
+        CookieJarClient::with_clock(backend, jar, SystemClock)
+    }
+}
+
+impl<B, C> CookieJarClient<B, C> {
+    /// Create a new [`CookieJarClient`] with the jar and the clock.
+    pub fn with_clock(backend: B, jar: CookieJar, clock: C) -> Self {
+        CookieJarClient {
+            backend,
+            jar: Mutex::new(jar),
+            clock,
+        }
+    }
+
+    /// The inner backend.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// The snapshot of the jar, to be persisted.
+    pub fn jar(&self) -> CookieJar {
+        self.jar.lock().unwrap().clone()
+    }
+}
+
+impl<B: ClientBackend, C: Clock> ClientBackend for CookieJarClient<B, C> {
+    type Error = B::Error;
+
+    async fn send(&self, mut request: RawClientRequest) -> Result<RawClientResponse, Self::Error> {
+        if request.header("Cookie").is_none() {
+            let cookie_header = self
+                .jar
+                .lock()
+                .unwrap()
+                .cookie_header(&request.url, self.clock.now());
+            if let Some(cookie_header) = cookie_header {
+                request.set_header("Cookie".to_string(), cookie_header);
+            }
+        }
+
+        let url = request.url.clone();
+        let response = self.backend.send(request).await?;
+        self.jar
+            .lock()
+            .unwrap()
+            .store_response(&url, &response.headers, self.clock.now());
+        Ok(response)
+    }
+}
+
+struct Url {
+    secure: bool,
+    host: String,
+    path: String,
+}
+
+impl Url {
+    fn parse(url: &str) -> Option<Self> {
+        let (scheme, rest) = url.split_once("://")?;
+        let authority_end = rest.find(['/', '?', '#']).unwrap_or(rest.len());
+        let (authority, rest) = rest.split_at(authority_end);
+        let host_port = authority.rsplit('@').next()?;
+        let host = match host_port.strip_prefix('[') {
+            Some(ipv6) => &host_port[..ipv6.find(']')? + 2],
+            None => host_port.split(':').next()?,
+        };
+        if host.is_empty() {
+            return None;
+        }
+        let path_end = rest.find(['?', '#']).unwrap_or(rest.len());
+        let path = if path_end == 0 {
+            "/"
+        } else {
+            &rest[..path_end]
+        };
+        Some(Url {
+            secure: scheme.eq_ignore_ascii_case("https"),
+            host: host.to_ascii_lowercase(),
+            path: path.to_string(),
+        })
+    }
+}
+
+fn domain_matches(host: &str, domain: &str) -> bool {
+    host == domain
+        || (host.ends_with(domain)
+            && host[..host.len() - domain.len()].ends_with('.')
+            && host.parse::<std::net::IpAddr>().is_err()
+            && !host.starts_with('['))
+}
+
+fn path_matches(request_path: &str, cookie_path: &str) -> bool {
+    request_path == cookie_path
+        || (request_path.starts_with(cookie_path)
+            && (cookie_path.ends_with('/') || request_path[cookie_path.len()..].starts_with('/')))
+}
+
+fn default_path(path: &str) -> String {
+    match path.rfind('/') {
+        Some(0) | None => "/".to_string(),
+        Some(index) => path[..index].to_string(),
+    }
+}
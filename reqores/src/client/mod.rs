@@ -1,5 +1,10 @@
-pub use client_request::{headers, ClientRequest};
-pub use client_response::ClientResponse;
+pub use client_backend::{BlockingClientBackend, ClientBackend, ClientCallError};
+pub use client_request::{headers, ClientRequest, RawClientRequest};
+pub use client_response::{ClientResponse, RawClientResponse};
 
+pub mod cache;
+pub mod cookie_jar;
+
+mod client_backend;
 mod client_request;
 mod client_response;
@@ -3,6 +3,7 @@
 /// The variants' documentations are taken from [MDN](https://developer.mozilla.org/en-US/docs/Web/HTTP/Methods),
 /// which is written by [Mozilla Contributors](https://developer.mozilla.org/en-US/docs/MDN/About/contributors.txt),
 /// licensed under [CC=BY-SA v2.5](https://creativecommons.org/licenses/by-sa/2.5/)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum HttpMethod {
     /// The GET method requests a representation of the specified resource. Requests using GET should only retrieve data.
     Get,
@@ -18,4 +19,75 @@ pub enum HttpMethod {
 
     /// The PATCH method applies partial modifications to a resource.
     Patch,
+
+    /// The HEAD method asks for a response identical to a GET request, but without the response body.
+    Head,
+
+    /// The OPTIONS method describes the communication options for the target resource.
+    Options,
+
+    /// The CONNECT method establishes a tunnel to the server identified by the target resource.
+    Connect,
+
+    /// The TRACE method performs a message loop-back test along the path to the target resource.
+    Trace,
+}
+
+impl HttpMethod {
+    /// The method name in uppercase, as written in the request line.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HttpMethod::Get => "GET",
+            HttpMethod::Post => "POST",
+            HttpMethod::Put => "PUT",
+            HttpMethod::Delete => "DELETE",
+            HttpMethod::Patch => "PATCH",
+            HttpMethod::Head => "HEAD",
+            HttpMethod::Options => "OPTIONS",
+            HttpMethod::Connect => "CONNECT",
+            HttpMethod::Trace => "TRACE",
+        }
+    }
+}
+
+impl core::fmt::Display for HttpMethod {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// The error type for parsing http method
+#[derive(Debug)]
+pub struct HttpMethodParseError {
+    /// The value accepted that is not a supported http method.
+    pub value: String,
+}
+
+impl std::error::Error for HttpMethodParseError {}
+
+impl core::fmt::Display for HttpMethodParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Invalid Method: {}", self.value)
+    }
+}
+
+impl core::str::FromStr for HttpMethod {
+    type Err = HttpMethodParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "GET" => Ok(HttpMethod::Get),
+            "POST" => Ok(HttpMethod::Post),
+            "PUT" => Ok(HttpMethod::Put),
+            "DELETE" => Ok(HttpMethod::Delete),
+            "PATCH" => Ok(HttpMethod::Patch),
+            "HEAD" => Ok(HttpMethod::Head),
+            "OPTIONS" => Ok(HttpMethod::Options),
+            "CONNECT" => Ok(HttpMethod::Connect),
+            "TRACE" => Ok(HttpMethod::Trace),
+            _ => Err(HttpMethodParseError {
+                value: value.to_string(),
+            }),
+        }
+    }
 }
@@ -8,7 +8,16 @@
 //! # Implementations
 //! 
 //! - [reqores-client-surf](https://crates.io/crates/reqores-client-surf) - surf based, async client implementation
+//! - [reqores-client-reqwest](https://crates.io/crates/reqores-client-reqwest) - reqwest based, async client implementation
+//! - [reqores-client-ureq](https://crates.io/crates/reqores-client-ureq) - ureq based, blocking client implementation
+//! - [reqores-client-hyper](https://crates.io/crates/reqores-client-hyper) - hyper based, async client implementation with connection pooling
+//! - [reqores-client-std](https://crates.io/crates/reqores-client-std) - std::net based, blocking client implementation for plain-HTTP local services
 //! - [reqores-universal-cf-worker](https://crates.io/crates/reqores-universal-cf-worker) - universal client/server implementation for Cloudflare Workers
+//! - [reqores-client-mock](https://crates.io/crates/reqores-client-mock) - mock, recording and replaying client implementations for tests
+//! - [reqores-server-std](https://crates.io/crates/reqores-server-std) - std::net based, blocking server implementation to run handlers locally
+//! - [reqores-server-hyper](https://crates.io/crates/reqores-server-hyper) - hyper based, async server implementation
+//! - [reqores-server-axum](https://crates.io/crates/reqores-server-axum) - extractor and responder to mount handlers on axum routes
+//! - [reqores-server-actix](https://crates.io/crates/reqores-server-actix) - server implementation for actix-web
 //! 
 //! # Example
 //! 
@@ -38,10 +47,16 @@
 
 #![deny(missing_docs)]
 
-pub use client::{headers, ClientRequest, ClientResponse};
-pub use http_method::HttpMethod;
+pub use client::{
+    cache, cookie_jar, headers, BlockingClientBackend, ClientBackend, ClientCallError,
+    ClientRequest, ClientResponse, RawClientRequest, RawClientResponse,
+};
+pub use http_method::{HttpMethod, HttpMethodParseError};
 pub use http_status_code::{HttpStatusCode, HttpStatusCodeParseError};
-pub use server::{ServerRequest, ServerResponse, ServerResponseBuilder};
+pub use server::{
+    wire, EntityTag, HeaderMerge, SameSite, ServerRequest, ServerResponse, ServerResponseBuilder,
+    SetCookie, TestServerRequest,
+};
 
 mod client;
 mod http_method;
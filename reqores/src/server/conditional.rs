@@ -0,0 +1,206 @@
+use std::time::SystemTime;
+
+use crate::{HttpStatusCode, ServerRequest, ServerResponse, ServerResponseBuilder};
+
+/// The entity tag validating a representation, used in "ETag" and "If-None-Match" headers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EntityTag {
+    /// Whether the tag is a weak validator, written with `W/` prefix.
+    pub weak: bool,
+    /// The opaque tag without quotes.
+    pub tag: String,
+}
+
+impl EntityTag {
+    /// Create a strong [`EntityTag`].
+    pub fn strong(tag: impl Into<String>) -> Self {
+        EntityTag {
+            weak: false,
+            tag: tag.into(),
+        }
+    }
+
+    /// Create a weak [`EntityTag`].
+    pub fn weak(tag: impl Into<String>) -> Self {
+        EntityTag {
+            weak: true,
+            tag: tag.into(),
+        }
+    }
+
+    /// Compute a strong [`EntityTag`] from the hash of the body.
+    ///
+    /// The hash is not cryptographic, it only distinguishes representations of the same resource.
+    pub fn from_body(body: &[u8]) -> Self {
+        let hash = body.iter().fold(0xcbf29ce484222325u64, |hash, byte| {
+            (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+        });
+        EntityTag::strong(format!("{:016x}", hash))
+    }
+
+    /// Parse a single entity tag like `"xyzzy"` or `W/"xyzzy"`.
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        let (weak, quoted) = match value.strip_prefix("W/") {
+            Some(quoted) => (true, quoted),
+            None => (false, value),
+        };
+        let tag = quoted.strip_prefix('"')?.strip_suffix('"')?;
+        if tag.contains('"') {
+            return None;
+        }
+        Some(EntityTag {
+            weak,
+            tag: tag.to_string(),
+        })
+    }
+
+    /// Strong comparison: both tags are strong and identical.
+    pub fn strong_eq(&self, other: &EntityTag) -> bool {
+        !self.weak && !other.weak && self.tag == other.tag
+    }
+
+    /// Weak comparison: the tags are identical regardless of weakness.
+    pub fn weak_eq(&self, other: &EntityTag) -> bool {
+        self.tag == other.tag
+    }
+}
+
+impl core::fmt::Display for EntityTag {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.weak {
+            write!(f, "W/\"{}\"", self.tag)
+        } else {
+            write!(f, "\"{}\"", self.tag)
+        }
+    }
+}
+
+impl ServerResponseBuilder {
+    /// Attach "ETag" header to the builder.
+    pub fn with_etag(self, etag: &EntityTag) -> Self {
+        self.with_header("ETag".to_string(), etag.to_string())
+    }
+
+    /// Attach "Last-Modified" header to the builder.
+    pub fn with_last_modified(self, time: SystemTime) -> Self {
+        self.with_header("Last-Modified".to_string(), httpdate::fmt_http_date(time))
+    }
+}
+
+impl ServerResponse {
+    /// The header value from the given key.
+    pub fn header(&self, key: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The entity tag from "ETag" header.
+    pub fn etag(&self) -> Option<EntityTag> {
+        self.header("ETag").and_then(EntityTag::parse)
+    }
+
+    /// Attach "ETag" header computed by [`EntityTag::from_body`] if the response has the body but no entity tag.
+    pub fn with_body_etag(mut self) -> Self {
+        if self.header("ETag").is_none() {
+            if let Some(body) = &self.body {
+                let etag = EntityTag::from_body(body);
+                self.headers.push(("ETag".to_string(), etag.to_string()));
+            }
+        }
+        self
+    }
+
+    /// Turn the response into a bodiless 304 Not Modified if the request already has the same representation.
+    ///
+    /// "If-None-Match" is evaluated with weak comparison, and "If-Modified-Since" is evaluated against "Last-Modified"
+    /// only when "If-None-Match" is absent.
+    /// Responses with non-2xx status are never turned into 304 Not Modified,
+    /// and the 304 Not Modified response has no body, so that adapters send no "Content-Length" either.
+    ///
+    /// Note that the conditions only make sense for GET and HEAD requests, so call it for those requests only.
+    pub fn evaluate_conditional(self, request: &impl ServerRequest) -> ServerResponse {
+        let status = self.status.clone().map(u16::from).unwrap_or(200);
+        if !(200..300).contains(&status) {
+            return self;
+        }
+
+        let not_modified = if let Some(if_none_match) = request.header("If-None-Match") {
+            match self.etag() {
+                _ if if_none_match.trim() == "*" => true,
+                Some(etag) => parse_entity_tags(&if_none_match)
+                    .iter()
+                    .any(|candidate| candidate.weak_eq(&etag)),
+                None => false,
+            }
+        } else if let (Some(if_modified_since), Some(last_modified)) = (
+            request.header("If-Modified-Since"),
+            self.header("Last-Modified"),
+        ) {
+            match (
+                httpdate::parse_http_date(&if_modified_since),
+                httpdate::parse_http_date(last_modified),
+            ) {
+                (Ok(if_modified_since), Ok(last_modified)) => last_modified <= if_modified_since,
+                _ => false,
+            }
+        } else {
+            false
+        };
+
+        if !not_modified {
+            return self;
+        }
+
+        ServerResponse {
+            status: Some(HttpStatusCode::NotModified),
+            headers: self
+                .headers
+                .into_iter()
+                .filter(|(name, _)| {
+                    ![
+                        "Content-Type",
+                        "Content-Length",
+                        "Content-Encoding",
+                        "Transfer-Encoding",
+                    ]
+                    .iter()
+                    .any(|content_header| name.eq_ignore_ascii_case(content_header))
+                })
+                .collect(),
+            body: None,
+        }
+    }
+}
+
+/// Parse the list of entity tags like `"a", W/"b,c"`, where the quoted tags may contain commas.
+///
+/// Malformed members are skipped.
+fn parse_entity_tags(value: &str) -> Vec<EntityTag> {
+    let mut tags = Vec::new();
+    let mut rest = value;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+        if rest.is_empty() {
+            return tags;
+        }
+        let (weak, quoted) = match rest.strip_prefix("W/") {
+            Some(quoted) => (true, quoted),
+            None => (false, rest),
+        };
+        let Some(tag_and_rest) = quoted.strip_prefix('"') else {
+            rest = rest.find(',').map_or("", |index| &rest[index..]);
+            continue;
+        };
+        let Some(end) = tag_and_rest.find('"') else {
+            return tags;
+        };
+        tags.push(EntityTag {
+            weak,
+            tag: tag_and_rest[..end].to_string(),
+        });
+        rest = &tag_and_rest[end + 1..];
+    }
+}
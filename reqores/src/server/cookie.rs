@@ -0,0 +1,208 @@
+use std::time::{Duration, SystemTime};
+
+use crate::ServerResponseBuilder;
+
+/// The "SameSite" attribute of [`SetCookie`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SameSite {
+    /// The cookie is only sent for same-site requests.
+    Strict,
+    /// The cookie is also sent when navigating to the site from other sites.
+    Lax,
+    /// The cookie is sent for cross-site requests too, which requires "Secure".
+    None,
+}
+
+/// The builder for "Set-Cookie" header value.
+///
+/// Note that the name and the value are written as-is, so they should not contain `;`, `,` or whitespaces.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetCookie {
+    name: String,
+    value: String,
+    path: Option<String>,
+    domain: Option<String>,
+    max_age: Option<Duration>,
+    expires: Option<SystemTime>,
+    secure: bool,
+    http_only: bool,
+    same_site: Option<SameSite>,
+    partitioned: bool,
+}
+
+impl SetCookie {
+    /// Create a new [`SetCookie`] with the name and the value.
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        SetCookie {
+            name: name.into(),
+            value: value.into(),
+            path: None,
+            domain: None,
+            max_age: None,
+            expires: None,
+            secure: false,
+            http_only: false,
+            same_site: None,
+            partitioned: false,
+        }
+    }
+
+    /// Create a new [`SetCookie`] removing the cookie with the name from the user agent.
+    pub fn removal(name: impl Into<String>) -> Self {
+        SetCookie::new(name, "")
+            .with_max_age(Duration::ZERO)
+            .with_expires(SystemTime::UNIX_EPOCH)
+    }
+
+    /// Set "Path" attribute.
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Set "Domain" attribute.
+    pub fn with_domain(mut self, domain: impl Into<String>) -> Self {
+        self.domain = Some(domain.into());
+        self
+    }
+
+    /// Set "Max-Age" attribute, truncated to seconds.
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Set "Expires" attribute.
+    pub fn with_expires(mut self, expires: SystemTime) -> Self {
+        self.expires = Some(expires);
+        self
+    }
+
+    /// Set "Secure" attribute.
+    pub fn secure(mut self) -> Self {
+        self.secure = true;
+        self
+    }
+
+    /// Set "HttpOnly" attribute.
+    pub fn http_only(mut self) -> Self {
+        self.http_only = true;
+        self
+    }
+
+    /// Set "SameSite" attribute.
+    pub fn with_same_site(mut self, same_site: SameSite) -> Self {
+        self.same_site = Some(same_site);
+        self
+    }
+
+    /// Set "Partitioned" attribute, which requires "Secure".
+    pub fn partitioned(mut self) -> Self {
+        self.partitioned = true;
+        self
+    }
+
+    /// Sign the value with HMAC-SHA256, to be verified with [`ServerRequest::signed_cookie`](`crate::ServerRequest::signed_cookie`).
+    #[cfg(feature = "signed-cookies")]
+    pub fn signed(mut self, key: &[u8]) -> Self {
+        self.value = signing::sign(&self.name, &self.value, key);
+        self
+    }
+}
+
+impl core::fmt::Display for SetCookie {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}={}", self.name, self.value)?;
+        if let Some(path) = &self.path {
+            write!(f, "; Path={}", path)?;
+        }
+        if let Some(domain) = &self.domain {
+            write!(f, "; Domain={}", domain)?;
+        }
+        if let Some(max_age) = self.max_age {
+            write!(f, "; Max-Age={}", max_age.as_secs())?;
+        }
+        if let Some(expires) = self.expires {
+            write!(f, "; Expires={}", httpdate::fmt_http_date(expires))?;
+        }
+        if self.secure {
+            write!(f, "; Secure")?;
+        }
+        if self.http_only {
+            write!(f, "; HttpOnly")?;
+        }
+        match self.same_site {
+            Some(SameSite::Strict) => write!(f, "; SameSite=Strict")?,
+            Some(SameSite::Lax) => write!(f, "; SameSite=Lax")?,
+            Some(SameSite::None) => write!(f, "; SameSite=None")?,
+            None => {}
+        }
+        if self.partitioned {
+            write!(f, "; Partitioned")?;
+        }
+        Ok(())
+    }
+}
+
+impl ServerResponseBuilder {
+    /// Attach "Set-Cookie" header to the builder.
+    pub fn with_cookie(self, cookie: &SetCookie) -> Self {
+        self.with_header("Set-Cookie".to_string(), cookie.to_string())
+    }
+}
+
+/// Parse "Cookie" header value into name-value pairs.
+pub(crate) fn parse_cookies(header: &str) -> Vec<(String, String)> {
+    header
+        .split(';')
+        .filter_map(|pair| {
+            let (name, value) = pair.split_once('=')?;
+            let name = name.trim();
+            if name.is_empty() {
+                return None;
+            }
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+                .unwrap_or(value);
+            Some((name.to_string(), value.to_string()))
+        })
+        .collect()
+}
+
+#[cfg(feature = "signed-cookies")]
+pub(crate) mod signing {
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    fn mac(name: &str, value: &str, key: &[u8]) -> Hmac<Sha256> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts keys of any size");
+        mac.update(name.as_bytes());
+        mac.update(b"=");
+        mac.update(value.as_bytes());
+        mac
+    }
+
+    pub(crate) fn sign(name: &str, value: &str, key: &[u8]) -> String {
+        let signature = mac(name, value, key).finalize().into_bytes();
+        let signature: String = signature.iter().map(|b| format!("{:02x}", b)).collect();
+        format!("{}.{}", value, signature)
+    }
+
+    pub(crate) fn verify(name: &str, signed_value: &str, key: &[u8]) -> Option<String> {
+        let (value, signature) = signed_value.rsplit_once('.')?;
+        if signature.len() != 64 || !signature.is_ascii() {
+            return None;
+        }
+        let signature = (0..signature.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&signature[i..i + 2], 16))
+            .collect::<Result<Vec<_>, _>>()
+            .ok()?;
+        mac(name, value, key)
+            .verify_slice(&signature)
+            .ok()
+            .map(|_| value.to_string())
+    }
+}
@@ -1,5 +1,12 @@
+pub use conditional::EntityTag;
+pub use cookie::{SameSite, SetCookie};
 pub use server_request::ServerRequest;
-pub use server_response::{ServerResponse, ServerResponseBuilder};
+pub use server_response::{HeaderMerge, ServerResponse, ServerResponseBuilder};
+pub use testing::TestServerRequest;
 
+mod conditional;
+mod cookie;
 mod server_request;
 mod server_response;
+mod testing;
+pub mod wire;
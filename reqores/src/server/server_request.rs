@@ -1,5 +1,9 @@
+use std::net::IpAddr;
+
 use serde::Deserialize;
 
+use super::cookie::parse_cookies;
+
 /// The request accepted by the server.
 pub trait ServerRequest {
     /// The body of the request.
@@ -12,4 +16,32 @@ pub trait ServerRequest {
 
     /// The header value from the given key.
     fn header(&self, key: &str) -> Option<String>;
+
+    /// The address of the client, if the server knows it.
+    ///
+    /// Behind a trusted proxy like Cloudflare, it is the original client rather than the proxy.
+    fn remote_addr(&self) -> Option<IpAddr> {
+        None
+    }
+
+    /// The cookies parsed from "Cookie" header.
+    fn cookies(&self) -> Vec<(String, String)> {
+        self.header("Cookie")
+            .map(|header| parse_cookies(&header))
+            .unwrap_or_default()
+    }
+
+    /// The cookie value from the given name.
+    fn cookie(&self, name: &str) -> Option<String> {
+        self.cookies()
+            .into_iter()
+            .find(|(cookie_name, _)| cookie_name == name)
+            .map(|(_, value)| value)
+    }
+
+    /// The cookie value from the given name, if it was signed with [`SetCookie::signed`](`crate::SetCookie::signed`) using the key.
+    #[cfg(feature = "signed-cookies")]
+    fn signed_cookie(&self, name: &str, key: &[u8]) -> Option<String> {
+        super::cookie::signing::verify(name, &self.cookie(name)?, key)
+    }
 }
@@ -2,9 +2,36 @@ use serde::Serialize;
 
 use crate::HttpStatusCode;
 
+/// The strategy to merge headers sharing the same name while composing [`ServerResponse`]s.
+///
+/// Header names are compared case-insensitively.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HeaderMerge {
+    /// Keep the earlier values and add the later ones.
+    Append,
+    /// Drop the earlier values in favor of the later ones.
+    Override,
+}
+
+impl HeaderMerge {
+    /// The strategy used by [`ServerResponse::then`] and [`ServerResponse::overlay`].
+    ///
+    /// "Set-Cookie" is appended since each value sets a different cookie, and every other header is overridden.
+    pub fn by_default(name: &str) -> HeaderMerge {
+        if name.eq_ignore_ascii_case("Set-Cookie") {
+            HeaderMerge::Append
+        } else {
+            HeaderMerge::Override
+        }
+    }
+}
+
 /// The response made from the server.
-/// 
-/// Note that the response could be partial and composable. 
+///
+/// Note that the response could be partial and composable.
+/// [`ServerResponse::default`] is the identity for both [`ServerResponse::then`] and [`ServerResponse::overlay`],
+/// and both compositions are associative.
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct ServerResponse {
     /// The status code of the response.
     pub status: Option<HttpStatusCode>,
@@ -17,19 +44,87 @@ pub struct ServerResponse {
 impl ServerResponse {
     /// Compose with next response.
     /// If self is having the body, the next response will be ignored.
+    ///
+    /// Otherwise, the status and the body of the next response take precedence,
+    /// and the headers are merged with [`HeaderMerge::by_default`].
+    ///
+    /// This is the way to put default headers in front of a handler:
+    ///
+    /// ```rust
+    /// # use reqores::{ServerResponse, ServerResponseBuilder};
+    /// let defaults = ServerResponseBuilder::new()
+    ///     .with_header("Cache-Control".to_string(), "no-cache".to_string())
+    ///     .end();
+    /// let response = defaults.then(ServerResponseBuilder::new().body_str("Hello"));
+    /// assert_eq!(response.body.as_deref(), Some(&b"Hello"[..]));
+    /// ```
     pub fn then(self, other: ServerResponse) -> ServerResponse {
+        self.then_with(other, HeaderMerge::by_default)
+    }
+
+    /// Compose with next response like [`ServerResponse::then`], deciding how to merge each header with `merge`.
+    pub fn then_with(
+        self,
+        other: ServerResponse,
+        merge: impl Fn(&str) -> HeaderMerge,
+    ) -> ServerResponse {
         if self.body.is_some() {
             self
         } else {
-            let mut headers = self.headers;
-            headers.extend(other.headers);
             ServerResponse {
                 status: other.status.or(self.status),
-                headers,
+                headers: merge_headers(self.headers, other.headers, merge),
                 body: other.body,
             }
         }
     }
+
+    /// Lay other response over self, even if self is having the body.
+    ///
+    /// The status and the body of other response take precedence if they exist,
+    /// and the headers are merged with [`HeaderMerge::by_default`].
+    ///
+    /// This is the way to finalize whatever a handler returned:
+    ///
+    /// ```rust
+    /// # use reqores::{ServerResponse, ServerResponseBuilder};
+    /// let finalizer = ServerResponseBuilder::new()
+    ///     .with_header("Server".to_string(), "reqores".to_string())
+    ///     .end();
+    /// let response = ServerResponseBuilder::new().body_str("Hello").overlay(finalizer);
+    /// assert_eq!(response.headers, vec![("Server".to_string(), "reqores".to_string())]);
+    /// ```
+    pub fn overlay(self, other: ServerResponse) -> ServerResponse {
+        self.overlay_with(other, HeaderMerge::by_default)
+    }
+
+    /// Lay other response over self like [`ServerResponse::overlay`], deciding how to merge each header with `merge`.
+    pub fn overlay_with(
+        self,
+        other: ServerResponse,
+        merge: impl Fn(&str) -> HeaderMerge,
+    ) -> ServerResponse {
+        ServerResponse {
+            status: other.status.or(self.status),
+            headers: merge_headers(self.headers, other.headers, merge),
+            body: other.body.or(self.body),
+        }
+    }
+}
+
+fn merge_headers(
+    mut headers: Vec<(String, String)>,
+    other: Vec<(String, String)>,
+    merge: impl Fn(&str) -> HeaderMerge,
+) -> Vec<(String, String)> {
+    headers.retain(|(name, _)| {
+        merge(name) == HeaderMerge::Append
+            || !other
+                .iter()
+                .any(|(other_name, _)| other_name.eq_ignore_ascii_case(name))
+    });
+    headers.extend(other);
+    headers
 }
 
 /// The utility builder for [`ServerResponse`].
@@ -0,0 +1,143 @@
+use std::net::IpAddr;
+
+use serde::Serialize;
+
+use crate::{HttpMethod, HttpStatusCode, ServerRequest, ServerResponse};
+
+/// The [`ServerRequest`] built in memory, to test handlers without any server.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TestServerRequest {
+    method: HttpMethod,
+    url: String,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+    remote_addr: Option<IpAddr>,
+}
+
+impl TestServerRequest {
+    /// Create a new [`TestServerRequest`] without headers and body.
+    pub fn new(method: HttpMethod, url: impl Into<String>) -> Self {
+        TestServerRequest {
+            method,
+            url: url.into(),
+            headers: Vec::new(),
+            body: Vec::new(),
+            remote_addr: None,
+        }
+    }
+
+    /// Attach header to the request.
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Set body to the request.
+    pub fn with_body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Set the address of the client.
+    pub fn with_remote_addr(mut self, remote_addr: IpAddr) -> Self {
+        self.remote_addr = Some(remote_addr);
+        self
+    }
+
+    /// Set body with serializable json content to the request.
+    pub fn with_json_body<T: Serialize>(self, body: &T) -> serde_json::Result<Self> {
+        Ok(self
+            .with_header("Content-Type", "application/json; charset=UTF-8")
+            .with_body(serde_json::to_vec(body)?))
+    }
+
+    /// The HTTP method of the request.
+    pub fn method(&self) -> HttpMethod {
+        self.method
+    }
+
+    /// The URL of the request.
+    pub fn url(&self) -> String {
+        self.url.clone()
+    }
+}
+
+impl ServerRequest for TestServerRequest {
+    fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    fn header(&self, key: &str) -> Option<String> {
+        self.headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value.clone())
+    }
+
+    fn remote_addr(&self) -> Option<IpAddr> {
+        self.remote_addr
+    }
+}
+
+/// Assertions for testing handlers, panicking with readable messages.
+impl ServerResponse {
+    /// Assert that the status is the given one, where the missing status is regarded as 200 OK.
+    #[track_caller]
+    pub fn assert_status(&self, expected: HttpStatusCode) -> &Self {
+        let actual = self.status.clone().unwrap_or(HttpStatusCode::Ok);
+        assert!(
+            actual == expected,
+            "expected status {}, got {}",
+            u16::from(expected),
+            u16::from(actual)
+        );
+        self
+    }
+
+    /// Assert that the header exists.
+    #[track_caller]
+    pub fn assert_header_present(&self, name: &str) -> &Self {
+        assert!(
+            self.header(name).is_some(),
+            "expected header {:?} to be present, got headers {:?}",
+            name,
+            self.headers
+        );
+        self
+    }
+
+    /// Assert that the header exists with the value.
+    #[track_caller]
+    pub fn assert_header(&self, name: &str, expected: &str) -> &Self {
+        assert!(
+            self.header(name) == Some(expected),
+            "expected header {:?} to be {:?}, got {:?}",
+            name,
+            expected,
+            self.header(name)
+        );
+        self
+    }
+
+    /// Assert that the body is JSON equal to the value, regardless of formatting.
+    #[track_caller]
+    pub fn assert_json_body<T: Serialize>(&self, expected: &T) -> &Self {
+        let expected =
+            serde_json::to_value(expected).expect("expected body should be serializable");
+        let body = self.body.as_deref().unwrap_or_default();
+        match serde_json::from_slice::<serde_json::Value>(body) {
+            Ok(actual) => assert!(
+                actual == expected,
+                "expected json body {}, got {}",
+                expected,
+                actual
+            ),
+            Err(_) => panic!(
+                "expected json body {}, got non-json {:?}",
+                expected,
+                String::from_utf8_lossy(body)
+            ),
+        }
+        self
+    }
+}
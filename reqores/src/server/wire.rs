@@ -0,0 +1,587 @@
+//! The HTTP/1.1 wire format of [`ServerRequest`] and [`ServerResponse`], and of responses for clients, without any runtime.
+//!
+//! ```rust
+//! use reqores::{wire::{BodyEncoding, WireLimits, WireRequest}, ServerRequest, ServerResponseBuilder};
+//!
+//! let input = b"GET /hello HTTP/1.1\r\nHost: example.com\r\n\r\n";
+//! let (request, consumed) = WireRequest::parse(input, &WireLimits::default()).unwrap();
+//! assert_eq!(request.url(), "http://example.com/hello");
+//! assert_eq!(consumed, input.len());
+//!
+//! let response = ServerResponseBuilder::new().body_str("Hello");
+//! assert_eq!(
+//!     response.to_wire(BodyEncoding::ContentLength).unwrap(),
+//!     b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nHello"
+//! );
+//! ```
+
+use std::net::IpAddr;
+
+use crate::{HttpMethod, HttpStatusCode, RawClientResponse, ServerRequest, ServerResponse};
+
+/// The limits applied while parsing, to reject oversized input before buffering it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WireLimits {
+    /// The maximum number of header fields, also applied to the trailers of the chunked body.
+    pub max_headers: usize,
+    /// The maximum length of the request line, each header line and each chunk size line, including CRLF.
+    pub max_line_size: usize,
+    /// The maximum length of the decoded body.
+    pub max_body_size: usize,
+}
+
+impl Default for WireLimits {
+    fn default() -> Self {
+        WireLimits {
+            max_headers: 64,
+            max_line_size: 8 * 1024,
+            max_body_size: 1024 * 1024,
+        }
+    }
+}
+
+/// The request parsed from HTTP/1.1 wire bytes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WireRequest {
+    method: HttpMethod,
+    target: String,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+    remote_addr: Option<IpAddr>,
+}
+
+impl WireRequest {
+    /// Parse the request at the start of the input, returning it with the number of bytes consumed.
+    ///
+    /// [`WireError::Incomplete`] is returned when the input ends before the request does,
+    /// so that the caller can read more bytes and try again.
+    pub fn parse(input: &[u8], limits: &WireLimits) -> Result<(WireRequest, usize), WireError> {
+        let mut cursor = Cursor {
+            input,
+            position: 0,
+            limits,
+        };
+
+        // RFC 9112 asks servers to ignore at least one empty line before the request line.
+        let mut request_line = cursor.line(WireError::InvalidRequestLine)?;
+        while request_line.is_empty() {
+            request_line = cursor.line(WireError::InvalidRequestLine)?;
+        }
+        let (method, target, version) = parse_request_line(request_line)?;
+        let headers = cursor.headers()?;
+
+        let hosts = headers
+            .iter()
+            .filter(|(name, _)| name.eq_ignore_ascii_case("Host"))
+            .count();
+        if version == "HTTP/1.1" && hosts != 1 {
+            return Err(WireError::InvalidHost);
+        }
+
+        let body = cursor.body(&headers, false)?;
+
+        Ok((
+            WireRequest {
+                method,
+                target: target.to_string(),
+                headers,
+                body,
+                remote_addr: None,
+            },
+            cursor.position,
+        ))
+    }
+
+    /// Set the address of the peer the request was read from, since it is not on the wire.
+    pub fn with_remote_addr(mut self, remote_addr: IpAddr) -> Self {
+        self.remote_addr = Some(remote_addr);
+        self
+    }
+
+    /// The request target as it was on the request line, like "/path?query".
+    pub fn target(&self) -> &str {
+        &self.target
+    }
+
+    /// Every header field in the order received.
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// The HTTP method of the request.
+    pub fn method(&self) -> HttpMethod {
+        self.method
+    }
+
+    /// The absolute URL, made of the "Host" header and the target unless the target is already absolute.
+    pub fn url(&self) -> String {
+        if self.target.starts_with("http://") || self.target.starts_with("https://") {
+            return self.target.clone();
+        }
+        match self.header("Host") {
+            Some(host) => format!("http://{}{}", host, self.target),
+            None => self.target.clone(),
+        }
+    }
+}
+
+impl ServerRequest for WireRequest {
+    fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    fn header(&self, key: &str) -> Option<String> {
+        self.headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value.clone())
+    }
+
+    fn remote_addr(&self) -> Option<IpAddr> {
+        self.remote_addr
+    }
+}
+
+/// The response parsed from HTTP/1.1 wire bytes, for clients.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WireResponse {
+    status: HttpStatusCode,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl WireResponse {
+    /// Parse the response to the request with the method, returning it with the number of bytes consumed.
+    ///
+    /// The response without "Content-Length" nor "Transfer-Encoding" takes the rest of the input as the body,
+    /// so the input should be everything read until the server closed the connection.
+    /// Interim 1xx responses are skipped.
+    pub fn parse(
+        input: &[u8],
+        limits: &WireLimits,
+        method: HttpMethod,
+    ) -> Result<(WireResponse, usize), WireError> {
+        let mut cursor = Cursor {
+            input,
+            position: 0,
+            limits,
+        };
+        loop {
+            let status = parse_status_line(cursor.line(WireError::InvalidStatusLine)?)?;
+            let headers = cursor.headers()?;
+            let code = u16::from(status.clone());
+            if code < 200 {
+                continue;
+            }
+
+            let body = if method == HttpMethod::Head || code == 204 || code == 304 {
+                Vec::new()
+            } else {
+                cursor.body(&headers, true)?
+            };
+            return Ok((
+                WireResponse {
+                    status,
+                    headers,
+                    body,
+                },
+                cursor.position,
+            ));
+        }
+    }
+
+    /// The status of the response.
+    pub fn status(&self) -> HttpStatusCode {
+        self.status.clone()
+    }
+
+    /// Every header field in the order received.
+    pub fn headers(&self) -> &[(String, String)] {
+        &self.headers
+    }
+
+    /// The body of the response.
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+}
+
+impl From<WireResponse> for RawClientResponse {
+    fn from(response: WireResponse) -> Self {
+        RawClientResponse {
+            status: response.status,
+            headers: response.headers,
+            body: response.body,
+        }
+    }
+}
+
+/// The way to frame the body of the serialized response.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BodyEncoding {
+    /// Send "Content-Length" and the body as-is.
+    ContentLength,
+    /// Send "Transfer-Encoding: chunked" and the body as a single chunk.
+    Chunked,
+}
+
+impl ServerResponse {
+    /// Serialize the response to HTTP/1.1 wire bytes, where the missing status is regarded as 200 OK.
+    ///
+    /// "Content-Length" and "Transfer-Encoding" headers are replaced with the framing of the encoding,
+    /// and 1xx, 204 No Content and 304 Not Modified responses are sent without any body.
+    pub fn to_wire(&self, encoding: BodyEncoding) -> Result<Vec<u8>, WireError> {
+        let status = self.status.clone().unwrap_or(HttpStatusCode::Ok);
+        let reason = status.reason_phrase();
+        let code = u16::from(status);
+
+        let mut output = format!("HTTP/1.1 {} {}\r\n", code, reason).into_bytes();
+        for (name, value) in &self.headers {
+            if !is_token(name.as_bytes()) || !is_field_value(value.as_bytes()) {
+                return Err(WireError::InvalidHeader);
+            }
+            if name.eq_ignore_ascii_case("Content-Length")
+                || name.eq_ignore_ascii_case("Transfer-Encoding")
+            {
+                continue;
+            }
+            output.extend_from_slice(format!("{}: {}\r\n", name, value).as_bytes());
+        }
+
+        let body = self.body.as_deref().unwrap_or_default();
+        if code < 200 || code == 204 || code == 304 {
+            output.extend_from_slice(b"\r\n");
+            return Ok(output);
+        }
+        match encoding {
+            BodyEncoding::ContentLength => {
+                output.extend_from_slice(
+                    format!("Content-Length: {}\r\n\r\n", body.len()).as_bytes(),
+                );
+                output.extend_from_slice(body);
+            }
+            BodyEncoding::Chunked => {
+                output.extend_from_slice(b"Transfer-Encoding: chunked\r\n\r\n");
+                if !body.is_empty() {
+                    output.extend_from_slice(format!("{:X}\r\n", body.len()).as_bytes());
+                    output.extend_from_slice(body);
+                    output.extend_from_slice(b"\r\n");
+                }
+                output.extend_from_slice(b"0\r\n\r\n");
+            }
+        }
+        Ok(output)
+    }
+}
+
+/// The error type for the HTTP/1.1 wire format.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WireError {
+    /// The input ended before the request did.
+    Incomplete,
+    /// The request line is not "METHOD SP target SP HTTP/x.y".
+    InvalidRequestLine,
+    /// The status line is not "HTTP/x.y SP 3DIGIT SP reason".
+    InvalidStatusLine,
+    /// The status is a valid number, but not the one [`HttpStatusCode`] knows.
+    UnknownStatus(u16),
+    /// The method is a valid token, but not the one [`HttpMethod`] knows.
+    UnknownMethod(String),
+    /// The HTTP version is neither 1.0 nor 1.1.
+    UnsupportedVersion(String),
+    /// The header line is malformed, or the header name or value contains forbidden characters.
+    InvalidHeader,
+    /// The HTTP/1.1 request has no "Host" header or more than one.
+    InvalidHost,
+    /// There are more header fields than [`WireLimits::max_headers`].
+    TooManyHeaders,
+    /// The line is longer than [`WireLimits::max_line_size`].
+    LineTooLong,
+    /// The "Content-Length" is not a number, or its values disagree.
+    InvalidContentLength,
+    /// Both "Content-Length" and "Transfer-Encoding" are present.
+    AmbiguousLength,
+    /// The "Transfer-Encoding" is not exactly "chunked".
+    UnsupportedTransferEncoding(String),
+    /// The chunk size line or the chunk delimiter is malformed.
+    InvalidChunk,
+    /// The body is longer than [`WireLimits::max_body_size`].
+    BodyTooLarge,
+}
+
+impl WireError {
+    /// The status to respond with for the request error, or [`None`] if more input is needed.
+    pub fn status(&self) -> Option<HttpStatusCode> {
+        match self {
+            WireError::Incomplete => None,
+            WireError::UnknownMethod(_) | WireError::UnsupportedTransferEncoding(_) => {
+                Some(HttpStatusCode::NotImplemented)
+            }
+            WireError::UnsupportedVersion(_) => Some(HttpStatusCode::HttpVersionNotSupported),
+            WireError::TooManyHeaders | WireError::LineTooLong => {
+                Some(HttpStatusCode::RequestHeaderFieldsTooLarge)
+            }
+            WireError::BodyTooLarge => Some(HttpStatusCode::PayloadTooLarge),
+            _ => Some(HttpStatusCode::BadRequest),
+        }
+    }
+}
+
+impl std::error::Error for WireError {}
+
+impl core::fmt::Display for WireError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            WireError::Incomplete => write!(f, "Incomplete Input"),
+            WireError::InvalidRequestLine => write!(f, "Invalid Request Line"),
+            WireError::InvalidStatusLine => write!(f, "Invalid Status Line"),
+            WireError::UnknownStatus(status) => write!(f, "Unknown Status: {}", status),
+            WireError::UnknownMethod(method) => write!(f, "Unknown Method: {}", method),
+            WireError::UnsupportedVersion(version) => write!(f, "Unsupported Version: {}", version),
+            WireError::InvalidHeader => write!(f, "Invalid Header"),
+            WireError::InvalidHost => write!(f, "Invalid Host"),
+            WireError::TooManyHeaders => write!(f, "Too Many Headers"),
+            WireError::LineTooLong => write!(f, "Line Too Long"),
+            WireError::InvalidContentLength => write!(f, "Invalid Content-Length"),
+            WireError::AmbiguousLength => {
+                write!(f, "Both Content-Length and Transfer-Encoding are present")
+            }
+            WireError::UnsupportedTransferEncoding(encoding) => {
+                write!(f, "Unsupported Transfer-Encoding: {}", encoding)
+            }
+            WireError::InvalidChunk => write!(f, "Invalid Chunk"),
+            WireError::BodyTooLarge => write!(f, "Body Too Large"),
+        }
+    }
+}
+
+struct Cursor<'a> {
+    input: &'a [u8],
+    position: usize,
+    limits: &'a WireLimits,
+}
+
+impl<'a> Cursor<'a> {
+    /// The next line without CRLF, failing with `invalid` if it ends with bare LF.
+    fn line(&mut self, invalid: WireError) -> Result<&'a [u8], WireError> {
+        let rest = &self.input[self.position..];
+        let window = &rest[..rest.len().min(self.limits.max_line_size)];
+        let Some(end) = window.iter().position(|&byte| byte == b'\n') else {
+            return Err(if rest.len() >= self.limits.max_line_size {
+                WireError::LineTooLong
+            } else {
+                WireError::Incomplete
+            });
+        };
+        if end == 0 || rest[end - 1] != b'\r' {
+            return Err(invalid);
+        }
+        self.position += end + 1;
+        Ok(&rest[..end - 1])
+    }
+
+    fn take(&mut self, length: usize) -> Result<&'a [u8], WireError> {
+        let rest = &self.input[self.position..];
+        if rest.len() < length {
+            return Err(WireError::Incomplete);
+        }
+        self.position += length;
+        Ok(&rest[..length])
+    }
+
+    /// The header fields up to the empty line.
+    fn headers(&mut self) -> Result<Vec<(String, String)>, WireError> {
+        let mut headers = Vec::new();
+        loop {
+            let line = self.line(WireError::InvalidHeader)?;
+            if line.is_empty() {
+                return Ok(headers);
+            }
+            if headers.len() >= self.limits.max_headers {
+                return Err(WireError::TooManyHeaders);
+            }
+            headers.push(parse_header(line)?);
+        }
+    }
+
+    /// The body framed by the headers, or the rest of the input if unframed and `until_end` is set.
+    fn body(
+        &mut self,
+        headers: &[(String, String)],
+        until_end: bool,
+    ) -> Result<Vec<u8>, WireError> {
+        let header_values = |key: &str| {
+            headers
+                .iter()
+                .filter(|(name, _)| name.eq_ignore_ascii_case(key))
+                .map(|(_, value)| value.as_str())
+                .collect::<Vec<_>>()
+        };
+        let transfer_encoding = header_values("Transfer-Encoding");
+        let content_length = header_values("Content-Length");
+        if !transfer_encoding.is_empty() {
+            if !content_length.is_empty() {
+                return Err(WireError::AmbiguousLength);
+            }
+            let encoding = transfer_encoding.join(", ");
+            if !encoding.eq_ignore_ascii_case("chunked") {
+                return Err(WireError::UnsupportedTransferEncoding(encoding));
+            }
+            self.chunked_body()
+        } else if !content_length.is_empty() {
+            let length = parse_content_length(&content_length)?;
+            if length > self.limits.max_body_size {
+                return Err(WireError::BodyTooLarge);
+            }
+            Ok(self.take(length)?.to_vec())
+        } else if until_end {
+            let length = self.input.len() - self.position;
+            if length > self.limits.max_body_size {
+                return Err(WireError::BodyTooLarge);
+            }
+            Ok(self.take(length)?.to_vec())
+        } else {
+            Ok(Vec::new())
+        }
+    }
+
+    fn chunked_body(&mut self) -> Result<Vec<u8>, WireError> {
+        let mut body = Vec::new();
+        loop {
+            let line = self.line(WireError::InvalidChunk)?;
+            let size = line.split(|&byte| byte == b';').next().unwrap_or_default();
+            let size = trim(size);
+            if size.is_empty() || size.len() > 16 || !size.iter().all(u8::is_ascii_hexdigit) {
+                return Err(WireError::InvalidChunk);
+            }
+            // Safe to unwrap, since the size is at most 16 hex digits.
+            let size = u64::from_str_radix(std::str::from_utf8(size).unwrap(), 16).unwrap();
+            if size == 0 {
+                break;
+            }
+            let size = usize::try_from(size).map_err(|_| WireError::BodyTooLarge)?;
+            if size > self.limits.max_body_size - body.len() {
+                return Err(WireError::BodyTooLarge);
+            }
+            body.extend_from_slice(self.take(size)?);
+            if self.take(2)? != b"\r\n" {
+                return Err(WireError::InvalidChunk);
+            }
+        }
+        // Trailers are read to find the end of the request, but dropped.
+        self.headers()?;
+        Ok(body)
+    }
+}
+
+fn parse_request_line(line: &[u8]) -> Result<(HttpMethod, &str, &str), WireError> {
+    let line = std::str::from_utf8(line).map_err(|_| WireError::InvalidRequestLine)?;
+    let mut parts = line.split(' ');
+    let (Some(method), Some(target), Some(version), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(WireError::InvalidRequestLine);
+    };
+
+    if !is_token(method.as_bytes())
+        || target.is_empty()
+        || !target.bytes().all(|byte| byte.is_ascii_graphic())
+    {
+        return Err(WireError::InvalidRequestLine);
+    }
+    let version_number = version
+        .strip_prefix("HTTP/")
+        .filter(|number| {
+            let number = number.as_bytes();
+            number.len() == 3
+                && number[0].is_ascii_digit()
+                && number[1] == b'.'
+                && number[2].is_ascii_digit()
+        })
+        .ok_or(WireError::InvalidRequestLine)?;
+    if version_number != "1.1" && version_number != "1.0" {
+        return Err(WireError::UnsupportedVersion(version.to_string()));
+    }
+    let method = method
+        .parse()
+        .map_err(|_| WireError::UnknownMethod(method.to_string()))?;
+
+    Ok((method, target, version))
+}
+
+fn parse_status_line(line: &[u8]) -> Result<HttpStatusCode, WireError> {
+    let line = std::str::from_utf8(line).map_err(|_| WireError::InvalidStatusLine)?;
+    let mut parts = line.splitn(3, ' ');
+    let (Some(version), Some(code)) = (parts.next(), parts.next()) else {
+        return Err(WireError::InvalidStatusLine);
+    };
+    if version != "HTTP/1.1" && version != "HTTP/1.0" {
+        return Err(WireError::InvalidStatusLine);
+    }
+    if code.len() != 3 || !code.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(WireError::InvalidStatusLine);
+    }
+    // Safe to unwrap, since the code is 3 digits.
+    let code = code.parse::<u16>().unwrap();
+    HttpStatusCode::try_from(code).map_err(|_| WireError::UnknownStatus(code))
+}
+
+fn parse_header(line: &[u8]) -> Result<(String, String), WireError> {
+    let colon = line
+        .iter()
+        .position(|&byte| byte == b':')
+        .ok_or(WireError::InvalidHeader)?;
+    let (name, value) = (&line[..colon], trim(&line[colon + 1..]));
+    if !is_token(name) || !is_field_value(value) {
+        return Err(WireError::InvalidHeader);
+    }
+    let value = String::from_utf8(value.to_vec()).map_err(|_| WireError::InvalidHeader)?;
+    // Safe to unwrap, since tokens are ASCII.
+    Ok((String::from_utf8(name.to_vec()).unwrap(), value))
+}
+
+fn parse_content_length(values: &[&str]) -> Result<usize, WireError> {
+    let mut lengths = values
+        .iter()
+        .flat_map(|value| value.split(','))
+        .map(|length| {
+            let length = length.trim();
+            if length.is_empty() || !length.bytes().all(|byte| byte.is_ascii_digit()) {
+                return Err(WireError::InvalidContentLength);
+            }
+            length.parse::<usize>().map_err(|_| WireError::BodyTooLarge)
+        });
+    let first = lengths.next().ok_or(WireError::InvalidContentLength)??;
+    for length in lengths {
+        if length? != first {
+            return Err(WireError::InvalidContentLength);
+        }
+    }
+    Ok(first)
+}
+
+fn trim(bytes: &[u8]) -> &[u8] {
+    let is_whitespace = |byte: &u8| *byte == b' ' || *byte == b'\t';
+    let start = bytes
+        .iter()
+        .position(|byte| !is_whitespace(byte))
+        .unwrap_or(bytes.len());
+    let end = bytes
+        .iter()
+        .rposition(|byte| !is_whitespace(byte))
+        .map_or(start, |end| end + 1);
+    &bytes[start..end]
+}
+
+fn is_token(bytes: &[u8]) -> bool {
+    !bytes.is_empty()
+        && bytes
+            .iter()
+            .all(|&byte| byte.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&byte))
+}
+
+fn is_field_value(bytes: &[u8]) -> bool {
+    bytes
+        .iter()
+        .all(|&byte| byte == b'\t' || (byte >= b' ' && byte != 0x7f))
+}
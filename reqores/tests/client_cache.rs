@@ -0,0 +1,261 @@
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+    time::{Duration, SystemTime},
+};
+
+use futures::executor::block_on;
+use reqores::{
+    cache::{CachingClient, MemoryCacheStorage},
+    ClientBackend, HttpMethod, HttpStatusCode, RawClientRequest, RawClientResponse,
+};
+
+#[derive(Default)]
+struct FakeBackend {
+    responses: RefCell<Vec<RawClientResponse>>,
+    requests: RefCell<Vec<RawClientRequest>>,
+}
+
+impl FakeBackend {
+    fn respond(&self, status: HttpStatusCode, headers: &[(&str, &str)], body: &str) {
+        self.responses.borrow_mut().push(RawClientResponse {
+            status,
+            headers: headers
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            body: body.as_bytes().to_vec(),
+        });
+    }
+}
+
+impl ClientBackend for FakeBackend {
+    type Error = ();
+
+    async fn send(&self, request: RawClientRequest) -> Result<RawClientResponse, ()> {
+        self.requests.borrow_mut().push(request);
+        Ok(self.responses.borrow_mut().remove(0))
+    }
+}
+
+struct FakeClock(Rc<Cell<SystemTime>>);
+
+impl FakeClock {
+    fn new() -> (Self, Rc<Cell<SystemTime>>) {
+        let now = Rc::new(Cell::new(SystemTime::UNIX_EPOCH));
+        (FakeClock(now.clone()), now)
+    }
+}
+
+impl reqores::cache::Clock for FakeClock {
+    fn now(&self) -> SystemTime {
+        self.0.get()
+    }
+}
+
+fn get(url: &str) -> RawClientRequest {
+    RawClientRequest {
+        method: HttpMethod::Get,
+        url: url.to_string(),
+        headers: vec![],
+        body: None,
+    }
+}
+
+#[test]
+fn test_serves_fresh_response() {
+    let backend = FakeBackend::default();
+    let (clock, now) = FakeClock::new();
+    let client = CachingClient::with_clock(&backend, MemoryCacheStorage::new(8), clock);
+
+    backend.respond(HttpStatusCode::Ok, &[("Cache-Control", "max-age=60")], "1");
+    backend.respond(HttpStatusCode::Ok, &[("Cache-Control", "max-age=60")], "2");
+
+    let first = block_on(client.send(get("https://example.com/"))).unwrap();
+    now.set(now.get() + Duration::from_secs(30));
+    let second = block_on(client.send(get("https://example.com/"))).unwrap();
+    assert_eq!(first, second);
+    assert_eq!(backend.requests.borrow().len(), 1);
+
+    now.set(now.get() + Duration::from_secs(30));
+    let third = block_on(client.send(get("https://example.com/"))).unwrap();
+    assert_eq!(third.body, b"2");
+    assert_eq!(backend.requests.borrow().len(), 2);
+}
+
+#[test]
+fn test_age_reduces_freshness() {
+    let backend = FakeBackend::default();
+    let (clock, now) = FakeClock::new();
+    let client = CachingClient::with_clock(&backend, MemoryCacheStorage::new(8), clock);
+
+    backend.respond(
+        HttpStatusCode::Ok,
+        &[("Cache-Control", "max-age=60"), ("Age", "50")],
+        "1",
+    );
+    backend.respond(HttpStatusCode::Ok, &[], "2");
+
+    block_on(client.send(get("https://example.com/"))).unwrap();
+    now.set(now.get() + Duration::from_secs(10));
+    let second = block_on(client.send(get("https://example.com/"))).unwrap();
+    assert_eq!(second.body, b"2");
+}
+
+#[test]
+fn test_no_store() {
+    let backend = FakeBackend::default();
+    let (clock, _) = FakeClock::new();
+    let client = CachingClient::with_clock(&backend, MemoryCacheStorage::new(8), clock);
+
+    backend.respond(
+        HttpStatusCode::Ok,
+        &[("Cache-Control", "no-store, max-age=60")],
+        "1",
+    );
+    backend.respond(HttpStatusCode::Ok, &[], "2");
+
+    block_on(client.send(get("https://example.com/"))).unwrap();
+    assert!(client.storage().is_empty());
+    let second = block_on(client.send(get("https://example.com/"))).unwrap();
+    assert_eq!(second.body, b"2");
+}
+
+#[test]
+fn test_revalidates_with_etag() {
+    let backend = FakeBackend::default();
+    let (clock, now) = FakeClock::new();
+    let client = CachingClient::with_clock(&backend, MemoryCacheStorage::new(8), clock);
+
+    backend.respond(
+        HttpStatusCode::Ok,
+        &[("Cache-Control", "no-cache"), ("ETag", "\"v1\"")],
+        "1",
+    );
+    backend.respond(
+        HttpStatusCode::NotModified,
+        &[("Cache-Control", "max-age=60"), ("ETag", "\"v1\"")],
+        "",
+    );
+
+    block_on(client.send(get("https://example.com/"))).unwrap();
+    now.set(now.get() + Duration::from_secs(1));
+    let second = block_on(client.send(get("https://example.com/"))).unwrap();
+    assert_eq!(second.status, HttpStatusCode::Ok);
+    assert_eq!(second.body, b"1");
+    assert_eq!(
+        backend.requests.borrow()[1].header("If-None-Match"),
+        Some("\"v1\"")
+    );
+
+    let third = block_on(client.send(get("https://example.com/"))).unwrap();
+    assert_eq!(third.body, b"1");
+    assert_eq!(backend.requests.borrow().len(), 2);
+}
+
+#[test]
+fn test_vary() {
+    let backend = FakeBackend::default();
+    let (clock, _) = FakeClock::new();
+    let client = CachingClient::with_clock(&backend, MemoryCacheStorage::new(8), clock);
+
+    backend.respond(
+        HttpStatusCode::Ok,
+        &[("Cache-Control", "max-age=60"), ("Vary", "Accept-Language")],
+        "en",
+    );
+    backend.respond(HttpStatusCode::Ok, &[], "ko");
+
+    let mut english = get("https://example.com/");
+    english.set_header("Accept-Language".to_string(), "en".to_string());
+    let mut korean = get("https://example.com/");
+    korean.set_header("Accept-Language".to_string(), "ko".to_string());
+
+    block_on(client.send(english.clone())).unwrap();
+    assert_eq!(block_on(client.send(english)).unwrap().body, b"en");
+    assert_eq!(block_on(client.send(korean)).unwrap().body, b"ko");
+}
+
+#[test]
+fn test_vary_variants_are_kept_apart() {
+    let backend = FakeBackend::default();
+    let (clock, _) = FakeClock::new();
+    let client = CachingClient::with_clock(&backend, MemoryCacheStorage::new(8), clock);
+
+    for body in ["en", "ko"] {
+        backend.respond(
+            HttpStatusCode::Ok,
+            &[("Cache-Control", "max-age=60"), ("Vary", "Accept-Language")],
+            body,
+        );
+    }
+
+    let mut english = get("https://example.com/");
+    english.set_header("Accept-Language".to_string(), "en".to_string());
+    let mut korean = get("https://example.com/");
+    korean.set_header("Accept-Language".to_string(), "ko".to_string());
+
+    for _ in 0..3 {
+        assert_eq!(block_on(client.send(english.clone())).unwrap().body, b"en");
+        assert_eq!(block_on(client.send(korean.clone())).unwrap().body, b"ko");
+    }
+    assert_eq!(backend.requests.borrow().len(), 2);
+    assert_eq!(client.storage().len(), 2);
+}
+
+#[test]
+fn test_unsafe_method_invalidates() {
+    let backend = FakeBackend::default();
+    let (clock, _) = FakeClock::new();
+    let client = CachingClient::with_clock(&backend, MemoryCacheStorage::new(8), clock);
+
+    backend.respond(HttpStatusCode::Ok, &[("Cache-Control", "max-age=60")], "1");
+    backend.respond(HttpStatusCode::NoContent, &[], "");
+    backend.respond(HttpStatusCode::Ok, &[("Cache-Control", "max-age=60")], "2");
+
+    block_on(client.send(get("https://example.com/"))).unwrap();
+    let mut post = get("https://example.com/");
+    post.method = HttpMethod::Post;
+    block_on(client.send(post)).unwrap();
+    let third = block_on(client.send(get("https://example.com/"))).unwrap();
+    assert_eq!(third.body, b"2");
+}
+
+#[test]
+fn test_safe_method_keeps_entry() {
+    let backend = FakeBackend::default();
+    let (clock, _) = FakeClock::new();
+    let client = CachingClient::with_clock(&backend, MemoryCacheStorage::new(8), clock);
+
+    backend.respond(HttpStatusCode::Ok, &[("Cache-Control", "max-age=60")], "1");
+    backend.respond(HttpStatusCode::Ok, &[], "");
+
+    block_on(client.send(get("https://example.com/"))).unwrap();
+    let mut head = get("https://example.com/");
+    head.method = HttpMethod::Head;
+    block_on(client.send(head)).unwrap();
+    let third = block_on(client.send(get("https://example.com/"))).unwrap();
+    assert_eq!(third.body, b"1");
+    assert_eq!(backend.requests.borrow().len(), 2);
+}
+
+#[test]
+fn test_memory_storage_evicts_least_recently_used() {
+    let backend = FakeBackend::default();
+    let (clock, _) = FakeClock::new();
+    let client = CachingClient::with_clock(&backend, MemoryCacheStorage::new(2), clock);
+
+    for body in ["a", "b", "c", "a"] {
+        backend.respond(HttpStatusCode::Ok, &[("Cache-Control", "max-age=60")], body);
+    }
+
+    block_on(client.send(get("https://example.com/a"))).unwrap();
+    block_on(client.send(get("https://example.com/b"))).unwrap();
+    block_on(client.send(get("https://example.com/a"))).unwrap();
+    block_on(client.send(get("https://example.com/c"))).unwrap();
+    assert_eq!(client.storage().len(), 2);
+    assert_eq!(backend.requests.borrow().len(), 3);
+
+    block_on(client.send(get("https://example.com/a"))).unwrap();
+    assert_eq!(backend.requests.borrow().len(), 3);
+}
@@ -0,0 +1,144 @@
+use std::time::{Duration, SystemTime};
+
+use reqores::{EntityTag, HttpStatusCode, ServerRequest, ServerResponse, ServerResponseBuilder};
+
+struct Request(Vec<(&'static str, String)>);
+
+impl ServerRequest for Request {
+    fn body(&self) -> &[u8] {
+        &[]
+    }
+
+    fn header(&self, key: &str) -> Option<String> {
+        self.0
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, value)| value.clone())
+    }
+}
+
+fn json_response() -> ServerResponse {
+    ServerResponseBuilder::new()
+        .body_json(&[1, 2, 3])
+        .unwrap()
+        .with_body_etag()
+}
+
+#[test]
+fn test_entity_tag_parse() {
+    assert_eq!(
+        EntityTag::parse("\"xyzzy\""),
+        Some(EntityTag::strong("xyzzy"))
+    );
+    assert_eq!(
+        EntityTag::parse(" W/\"xyzzy\" "),
+        Some(EntityTag::weak("xyzzy"))
+    );
+    assert_eq!(EntityTag::parse("xyzzy"), None);
+    assert_eq!(EntityTag::parse("\"xy\"zzy\""), None);
+    assert_eq!(EntityTag::weak("xyzzy").to_string(), "W/\"xyzzy\"");
+}
+
+#[test]
+fn test_entity_tag_comparison() {
+    let strong = EntityTag::strong("1");
+    let weak = EntityTag::weak("1");
+    assert!(strong.strong_eq(&EntityTag::strong("1")));
+    assert!(!strong.strong_eq(&weak));
+    assert!(!weak.strong_eq(&weak));
+    assert!(strong.weak_eq(&weak));
+    assert!(!weak.weak_eq(&EntityTag::weak("2")));
+}
+
+#[test]
+fn test_body_etag_is_stable() {
+    assert_eq!(json_response().etag(), json_response().etag());
+    assert_ne!(EntityTag::from_body(b"a"), EntityTag::from_body(b"b"),);
+}
+
+#[test]
+fn test_if_none_match() {
+    let etag = json_response().etag().unwrap();
+    let matching = Request(vec![(
+        "If-None-Match",
+        format!("\"other\", {}", EntityTag::weak(etag.tag.clone())),
+    )]);
+    let response = json_response().evaluate_conditional(&matching);
+    assert_eq!(response.status, Some(HttpStatusCode::NotModified));
+    assert_eq!(response.body, None);
+    assert_eq!(response.header("Content-Type"), None);
+    assert_eq!(response.etag(), Some(etag));
+
+    let comma = ServerResponseBuilder::new()
+        .with_etag(&EntityTag::strong("a,b"))
+        .body_str("Hello");
+    let quoted_comma = Request(vec![(
+        "If-None-Match",
+        "\"other\", \"a,b\", broken".to_string(),
+    )]);
+    assert_eq!(
+        comma.evaluate_conditional(&quoted_comma).status,
+        Some(HttpStatusCode::NotModified)
+    );
+
+    let unmatching = Request(vec![("If-None-Match", "\"other\"".to_string())]);
+    assert_eq!(
+        json_response().evaluate_conditional(&unmatching),
+        json_response()
+    );
+
+    let wildcard = Request(vec![("If-None-Match", "*".to_string())]);
+    assert_eq!(
+        json_response().evaluate_conditional(&wildcard).status,
+        Some(HttpStatusCode::NotModified)
+    );
+}
+
+#[test]
+fn test_if_modified_since() {
+    let last_modified = SystemTime::UNIX_EPOCH + Duration::from_secs(1_600_000_000);
+    let response = || {
+        ServerResponseBuilder::new()
+            .with_last_modified(last_modified)
+            .body_str("Hello")
+    };
+
+    let later = Request(vec![(
+        "If-Modified-Since",
+        httpdate::fmt_http_date(last_modified + Duration::from_secs(60)),
+    )]);
+    assert_eq!(
+        response().evaluate_conditional(&later).status,
+        Some(HttpStatusCode::NotModified)
+    );
+
+    let earlier = Request(vec![(
+        "If-Modified-Since",
+        httpdate::fmt_http_date(last_modified - Duration::from_secs(60)),
+    )]);
+    assert_eq!(response().evaluate_conditional(&earlier), response());
+
+    let invalid = Request(vec![("If-Modified-Since", "yesterday".to_string())]);
+    assert_eq!(response().evaluate_conditional(&invalid), response());
+
+    let both = Request(vec![
+        ("If-None-Match", "\"other\"".to_string()),
+        (
+            "If-Modified-Since",
+            httpdate::fmt_http_date(last_modified + Duration::from_secs(60)),
+        ),
+    ]);
+    assert_eq!(response().evaluate_conditional(&both), response());
+}
+
+#[test]
+fn test_non_success_is_untouched() {
+    let response = || {
+        ServerResponseBuilder::new()
+            .with_status(HttpStatusCode::Notfound)
+            .body_str("Not Found")
+            .with_body_etag()
+    };
+    let request = Request(vec![("If-None-Match", "*".to_string())]);
+    assert_eq!(response().evaluate_conditional(&request), response());
+}
@@ -0,0 +1,180 @@
+use std::{
+    cell::RefCell,
+    time::{Duration, SystemTime},
+};
+
+use futures::executor::block_on;
+use reqores::{
+    cookie_jar::{CookieJar, CookieJarClient, StoredCookie},
+    ClientBackend, HttpMethod, HttpStatusCode, RawClientRequest, RawClientResponse,
+};
+
+fn now() -> SystemTime {
+    SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000)
+}
+
+fn store(jar: &mut CookieJar, url: &str, set_cookie: &str) {
+    jar.store_response(
+        url,
+        &[("Set-Cookie".to_string(), set_cookie.to_string())],
+        now(),
+    );
+}
+
+#[test]
+fn test_parse_stored_cookie() {
+    let cookie = StoredCookie::parse(
+        "sid=abc; Domain=.Example.com; Path=/admin; Max-Age=60; Secure; HttpOnly",
+        "https://www.example.com/login",
+        now(),
+    )
+    .unwrap();
+    assert_eq!(
+        cookie,
+        StoredCookie {
+            name: "sid".to_string(),
+            value: "abc".to_string(),
+            domain: "example.com".to_string(),
+            host_only: false,
+            path: "/admin".to_string(),
+            expires: Some(now() + Duration::from_secs(60)),
+            secure: true,
+        }
+    );
+
+    let cookie = StoredCookie::parse("a=1", "http://example.com/a/b?c", now()).unwrap();
+    assert!(cookie.host_only);
+    assert_eq!(cookie.path, "/a");
+    assert_eq!(cookie.expires, None);
+
+    assert_eq!(
+        StoredCookie::parse("a=1; Domain=other.com", "https://example.com/", now()),
+        None
+    );
+    assert_eq!(
+        StoredCookie::parse("a=1; Domain=ample.com", "https://example.com/", now()),
+        None
+    );
+    assert_eq!(
+        StoredCookie::parse("novalue", "https://example.com/", now()),
+        None
+    );
+}
+
+#[test]
+fn test_domain_and_path_rules() {
+    let mut jar = CookieJar::new();
+    store(&mut jar, "https://example.com/", "host=1");
+    store(
+        &mut jar,
+        "https://example.com/",
+        "domain=2; Domain=example.com",
+    );
+    store(&mut jar, "https://example.com/", "admin=3; Path=/admin");
+    store(&mut jar, "https://example.com/", "secure=4; Secure");
+
+    assert_eq!(
+        jar.cookie_header("https://example.com/admin/users", now()),
+        Some("admin=3; host=1; domain=2; secure=4".to_string())
+    );
+    assert_eq!(
+        jar.cookie_header("http://example.com/administrator", now()),
+        Some("host=1; domain=2".to_string())
+    );
+    assert_eq!(
+        jar.cookie_header("https://api.example.com/", now()),
+        Some("domain=2".to_string())
+    );
+    assert_eq!(jar.cookie_header("https://example.org/", now()), None);
+}
+
+#[test]
+fn test_expiry_and_replacement() {
+    let mut jar = CookieJar::new();
+    store(&mut jar, "https://example.com/", "a=1; Max-Age=60");
+    store(&mut jar, "https://example.com/", "a=2; Max-Age=60");
+    assert_eq!(jar.cookies().len(), 1);
+    assert_eq!(
+        jar.cookie_header("https://example.com/", now()),
+        Some("a=2".to_string())
+    );
+    assert_eq!(
+        jar.cookie_header("https://example.com/", now() + Duration::from_secs(60)),
+        None
+    );
+
+    store(&mut jar, "https://example.com/", "a=; Max-Age=0");
+    assert!(jar.cookies().is_empty());
+
+    store(
+        &mut jar,
+        "https://example.com/",
+        "b=1; Expires=Thu, 01 Jan 1970 00:00:00 GMT",
+    );
+    assert!(jar.cookies().is_empty());
+
+    store(&mut jar, "https://example.com/", "c=1; Max-Age=10");
+    jar.remove_expired(now() + Duration::from_secs(10));
+    assert!(jar.cookies().is_empty());
+}
+
+#[test]
+fn test_overflowing_max_age_never_expires() {
+    let cookie = StoredCookie::parse(
+        "a=1; Max-Age=9223372036854775807",
+        "https://example.com/",
+        now(),
+    )
+    .unwrap();
+    assert_eq!(cookie.expires, None);
+}
+
+#[test]
+fn test_serialize_jar() {
+    let mut jar = CookieJar::new();
+    store(&mut jar, "https://example.com/", "a=1; Max-Age=60");
+    let json = serde_json::to_string(&jar).unwrap();
+    assert_eq!(serde_json::from_str::<CookieJar>(&json).unwrap(), jar);
+}
+
+#[derive(Default)]
+struct FakeBackend {
+    requests: RefCell<Vec<RawClientRequest>>,
+}
+
+impl ClientBackend for FakeBackend {
+    type Error = ();
+
+    async fn send(&self, request: RawClientRequest) -> Result<RawClientResponse, ()> {
+        self.requests.borrow_mut().push(request);
+        Ok(RawClientResponse {
+            status: HttpStatusCode::Ok,
+            headers: vec![("Set-Cookie".to_string(), "sid=abc".to_string())],
+            body: vec![],
+        })
+    }
+}
+
+#[test]
+fn test_cookie_jar_client() {
+    let backend = FakeBackend::default();
+    let client = CookieJarClient::with_clock(&backend, CookieJar::new(), now);
+    let request = RawClientRequest {
+        method: HttpMethod::Get,
+        url: "https://example.com/".to_string(),
+        headers: vec![],
+        body: None,
+    };
+
+    block_on(client.send(request.clone())).unwrap();
+    block_on(client.send(request.clone())).unwrap();
+    let mut explicit = request;
+    explicit.set_header("Cookie".to_string(), "mine=1".to_string());
+    block_on(client.send(explicit)).unwrap();
+
+    let requests = backend.requests.borrow();
+    assert_eq!(requests[0].header("Cookie"), None);
+    assert_eq!(requests[1].header("Cookie"), Some("sid=abc"));
+    assert_eq!(requests[2].header("Cookie"), Some("mine=1"));
+    assert_eq!(client.jar().cookies().len(), 1);
+}
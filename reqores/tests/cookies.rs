@@ -0,0 +1,96 @@
+use std::time::{Duration, SystemTime};
+
+use reqores::{SameSite, ServerRequest, ServerResponseBuilder, SetCookie};
+
+struct Request(Option<String>);
+
+impl ServerRequest for Request {
+    fn body(&self) -> &[u8] {
+        &[]
+    }
+
+    fn header(&self, key: &str) -> Option<String> {
+        if key.eq_ignore_ascii_case("Cookie") {
+            self.0.clone()
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn test_parse_cookies() {
+    let request = Request(Some(
+        "a=1; b=\"two\";c=;  =ignored; broken; d=x=y".to_string(),
+    ));
+    assert_eq!(
+        request.cookies(),
+        vec![
+            ("a".to_string(), "1".to_string()),
+            ("b".to_string(), "two".to_string()),
+            ("c".to_string(), "".to_string()),
+            ("d".to_string(), "x=y".to_string()),
+        ]
+    );
+    assert_eq!(request.cookie("b").as_deref(), Some("two"));
+    assert_eq!(request.cookie("e"), None);
+    assert!(Request(None).cookies().is_empty());
+}
+
+#[test]
+fn test_set_cookie() {
+    let cookie = SetCookie::new("session", "abc")
+        .with_path("/admin")
+        .with_domain("example.com")
+        .with_max_age(Duration::from_secs(3600))
+        .with_expires(SystemTime::UNIX_EPOCH + Duration::from_secs(784111777))
+        .secure()
+        .http_only()
+        .with_same_site(SameSite::Strict)
+        .partitioned();
+    assert_eq!(
+        cookie.to_string(),
+        "session=abc; Path=/admin; Domain=example.com; Max-Age=3600; Expires=Sun, 06 Nov 1994 08:49:37 GMT; Secure; HttpOnly; SameSite=Strict; Partitioned"
+    );
+    assert_eq!(SetCookie::new("a", "1").to_string(), "a=1");
+    assert_eq!(
+        SetCookie::removal("a").to_string(),
+        "a=; Max-Age=0; Expires=Thu, 01 Jan 1970 00:00:00 GMT"
+    );
+}
+
+#[test]
+fn test_with_cookie_appends() {
+    let response = ServerResponseBuilder::new()
+        .with_cookie(&SetCookie::new("a", "1"))
+        .with_cookie(&SetCookie::new("b", "2"))
+        .end();
+    assert_eq!(
+        response.headers,
+        vec![
+            ("Set-Cookie".to_string(), "a=1".to_string()),
+            ("Set-Cookie".to_string(), "b=2".to_string()),
+        ]
+    );
+}
+
+#[cfg(feature = "signed-cookies")]
+#[test]
+fn test_signed_cookie() {
+    let key = b"secret";
+    let cookie = SetCookie::new("user", "admin").signed(key).to_string();
+    let header = cookie.split(';').next().unwrap().to_string();
+    let request = Request(Some(header.clone()));
+    assert_eq!(request.signed_cookie("user", key).as_deref(), Some("admin"));
+    assert_eq!(request.signed_cookie("user", b"other"), None);
+
+    let renamed = Request(Some(header.replacen("user", "role", 1)));
+    assert_eq!(renamed.signed_cookie("role", key), None);
+
+    let tampered = Request(Some(header.replacen("admin", "admix", 1)));
+    assert_eq!(tampered.signed_cookie("user", key), None);
+    assert_eq!(
+        Request(Some("user=admin".to_string())).signed_cookie("user", key),
+        None
+    );
+}
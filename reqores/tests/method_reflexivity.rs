@@ -0,0 +1,19 @@
+use reqores::HttpMethod;
+
+#[test]
+fn test_reflexivity() {
+    for method in [
+        HttpMethod::Get,
+        HttpMethod::Post,
+        HttpMethod::Put,
+        HttpMethod::Delete,
+        HttpMethod::Patch,
+        HttpMethod::Head,
+        HttpMethod::Options,
+        HttpMethod::Connect,
+        HttpMethod::Trace,
+    ] {
+        assert_eq!(method.as_str().parse::<HttpMethod>().unwrap(), method);
+    }
+    assert!("get".parse::<HttpMethod>().is_err());
+}
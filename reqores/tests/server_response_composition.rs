@@ -0,0 +1,115 @@
+use proptest::prelude::*;
+use reqores::{HttpStatusCode, ServerResponse};
+
+fn status() -> impl Strategy<Value = Option<HttpStatusCode>> {
+    prop::option::of(prop::sample::select(vec![
+        HttpStatusCode::Ok,
+        HttpStatusCode::NoContent,
+        HttpStatusCode::BadRequest,
+        HttpStatusCode::InternalServerError,
+    ]))
+}
+
+fn header() -> impl Strategy<Value = (String, String)> {
+    (
+        prop::sample::select(vec![
+            "Content-Type",
+            "content-type",
+            "Cache-Control",
+            "Set-Cookie",
+            "set-cookie",
+        ]),
+        "[a-z]{1,3}",
+    )
+        .prop_map(|(name, value)| (name.to_string(), value))
+}
+
+fn response() -> impl Strategy<Value = ServerResponse> {
+    (
+        status(),
+        prop::collection::vec(header(), 0..4),
+        prop::option::of(prop::collection::vec(any::<u8>(), 0..4)),
+    )
+        .prop_map(|(status, headers, body)| ServerResponse {
+            status,
+            headers,
+            body,
+        })
+}
+
+proptest! {
+    #[test]
+    fn then_is_associative(a in response(), b in response(), c in response()) {
+        prop_assert_eq!(
+            a.clone().then(b.clone()).then(c.clone()),
+            a.then(b.then(c))
+        );
+    }
+
+    #[test]
+    fn then_has_identity(a in response()) {
+        prop_assert_eq!(ServerResponse::default().then(a.clone()), a.clone());
+        prop_assert_eq!(a.clone().then(ServerResponse::default()), a);
+    }
+
+    #[test]
+    fn overlay_is_associative(a in response(), b in response(), c in response()) {
+        prop_assert_eq!(
+            a.clone().overlay(b.clone()).overlay(c.clone()),
+            a.overlay(b.overlay(c))
+        );
+    }
+
+    #[test]
+    fn overlay_has_identity(a in response()) {
+        prop_assert_eq!(ServerResponse::default().overlay(a.clone()), a.clone());
+        prop_assert_eq!(a.clone().overlay(ServerResponse::default()), a);
+    }
+}
+
+#[test]
+fn test_then_ignores_next_after_body() {
+    let first = ServerResponse {
+        status: None,
+        headers: vec![],
+        body: Some(b"first".to_vec()),
+    };
+    let second = ServerResponse {
+        status: Some(HttpStatusCode::BadRequest),
+        headers: vec![("Content-Type".to_string(), "text/plain".to_string())],
+        body: Some(b"second".to_vec()),
+    };
+    assert_eq!(first.clone().then(second), first);
+}
+
+#[test]
+fn test_override_and_append() {
+    let first = ServerResponse {
+        status: Some(HttpStatusCode::Ok),
+        headers: vec![
+            ("Content-Type".to_string(), "text/plain".to_string()),
+            ("Set-Cookie".to_string(), "a=1".to_string()),
+        ],
+        body: None,
+    };
+    let second = ServerResponse {
+        status: None,
+        headers: vec![
+            ("content-type".to_string(), "application/json".to_string()),
+            ("Set-Cookie".to_string(), "b=2".to_string()),
+        ],
+        body: Some(b"{}".to_vec()),
+    };
+    assert_eq!(
+        first.then(second),
+        ServerResponse {
+            status: Some(HttpStatusCode::Ok),
+            headers: vec![
+                ("Set-Cookie".to_string(), "a=1".to_string()),
+                ("content-type".to_string(), "application/json".to_string()),
+                ("Set-Cookie".to_string(), "b=2".to_string()),
+            ],
+            body: Some(b"{}".to_vec()),
+        }
+    );
+}
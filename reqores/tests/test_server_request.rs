@@ -0,0 +1,118 @@
+use reqores::{
+    HttpMethod, HttpStatusCode, ServerRequest, ServerResponse, ServerResponseBuilder,
+    TestServerRequest,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Update {
+    id: u32,
+    text: String,
+}
+
+fn handler(request: &TestServerRequest) -> ServerResponse {
+    if request.method() != HttpMethod::Post || !request.url().ends_with("/webhook") {
+        return ServerResponseBuilder::new()
+            .with_status(HttpStatusCode::Notfound)
+            .end();
+    }
+    if request.header("X-Secret").as_deref() != Some("secret") {
+        return ServerResponseBuilder::new()
+            .with_status(HttpStatusCode::Unauthorized)
+            .end();
+    }
+    match request.body_json::<Update>() {
+        Ok(update) => ServerResponseBuilder::new()
+            .body_json(&Update {
+                id: update.id,
+                text: update.text.to_uppercase(),
+            })
+            .unwrap(),
+        Err(_) => ServerResponseBuilder::new()
+            .with_status(HttpStatusCode::BadRequest)
+            .end(),
+    }
+}
+
+fn update_request() -> TestServerRequest {
+    TestServerRequest::new(HttpMethod::Post, "https://example.com/webhook")
+        .with_header("X-Secret", "secret")
+        .with_json_body(&Update {
+            id: 1,
+            text: "hello".to_string(),
+        })
+        .unwrap()
+}
+
+#[test]
+fn test_request_builder() {
+    let request = update_request();
+    assert_eq!(request.method(), HttpMethod::Post);
+    assert_eq!(request.url(), "https://example.com/webhook");
+    assert_eq!(
+        request.header("content-type").as_deref(),
+        Some("application/json; charset=UTF-8")
+    );
+    assert_eq!(request.body(), br#"{"id":1,"text":"hello"}"#);
+}
+
+#[test]
+fn test_request_remote_addr() {
+    assert_eq!(update_request().remote_addr(), None);
+
+    let addr = "203.0.113.7".parse().unwrap();
+    let request = update_request().with_remote_addr(addr);
+    assert_eq!(request.remote_addr(), Some(addr));
+}
+
+#[test]
+fn test_handler_assertions() {
+    handler(&update_request())
+        .assert_status(HttpStatusCode::Ok)
+        .assert_header_present("Content-Type")
+        .assert_header("content-type", "application/json; charset=UTF-8")
+        .assert_json_body(&Update {
+            id: 1,
+            text: "HELLO".to_string(),
+        });
+
+    handler(&TestServerRequest::new(
+        HttpMethod::Post,
+        "https://example.com/webhook",
+    ))
+    .assert_status(HttpStatusCode::Unauthorized);
+
+    handler(
+        &TestServerRequest::new(HttpMethod::Post, "https://example.com/webhook")
+            .with_header("X-Secret", "secret")
+            .with_body("not json"),
+    )
+    .assert_status(HttpStatusCode::BadRequest);
+}
+
+#[test]
+#[should_panic(expected = "expected status 200, got 404")]
+fn test_assert_status_fails() {
+    handler(&TestServerRequest::new(
+        HttpMethod::Get,
+        "https://example.com/",
+    ))
+    .assert_status(HttpStatusCode::Ok);
+}
+
+#[test]
+#[should_panic(
+    expected = "expected json body {\"id\":1,\"text\":\"hello\"}, got {\"id\":1,\"text\":\"HELLO\"}"
+)]
+fn test_assert_json_body_fails() {
+    handler(&update_request()).assert_json_body(&Update {
+        id: 1,
+        text: "hello".to_string(),
+    });
+}
+
+#[test]
+#[should_panic(expected = "expected header \"X-Missing\" to be present")]
+fn test_assert_header_present_fails() {
+    handler(&update_request()).assert_header_present("X-Missing");
+}
@@ -0,0 +1,496 @@
+use proptest::prelude::*;
+use reqores::{
+    wire::{BodyEncoding, WireError, WireLimits, WireRequest, WireResponse},
+    HttpMethod, HttpStatusCode, ServerRequest, ServerResponseBuilder,
+};
+
+fn parse(input: &[u8]) -> Result<WireRequest, WireError> {
+    WireRequest::parse(input, &WireLimits::default()).map(|(request, _)| request)
+}
+
+#[test]
+fn parses_request_with_content_length() {
+    let input = b"POST /items?page=2 HTTP/1.1\r\nHost: example.com\r\nContent-Type: text/plain\r\nX-Multi: 1\r\nX-Multi: 2\r\nContent-Length: 5\r\n\r\nhello";
+
+    let (request, consumed) = WireRequest::parse(input, &WireLimits::default()).unwrap();
+
+    assert_eq!(consumed, input.len());
+    assert_eq!(request.method(), HttpMethod::Post);
+    assert_eq!(request.target(), "/items?page=2");
+    assert_eq!(request.url(), "http://example.com/items?page=2");
+    assert_eq!(
+        request.header("content-type").as_deref(),
+        Some("text/plain")
+    );
+    assert_eq!(
+        request
+            .headers()
+            .iter()
+            .filter(|(name, _)| name == "X-Multi")
+            .count(),
+        2
+    );
+    assert_eq!(request.body(), b"hello");
+}
+
+#[test]
+fn parses_chunked_request_with_trailers() {
+    let input = b"PUT / HTTP/1.1\r\nHost: a\r\nTransfer-Encoding: chunked\r\n\r\n5;ext=1\r\nhello\r\n6\r\n world\r\n0\r\nX-Trailer: yes\r\n\r\n";
+
+    let (request, consumed) = WireRequest::parse(input, &WireLimits::default()).unwrap();
+
+    assert_eq!(consumed, input.len());
+    assert_eq!(request.body(), b"hello world");
+    assert_eq!(request.header("X-Trailer"), None);
+}
+
+#[test]
+fn reports_consumed_bytes_for_pipelined_requests() {
+    let first = b"GET /1 HTTP/1.1\r\nHost: a\r\n\r\n".to_vec();
+    let second = b"GET /2 HTTP/1.1\r\nHost: a\r\n\r\n".to_vec();
+    let input = [first.clone(), second].concat();
+
+    let (request, consumed) = WireRequest::parse(&input, &WireLimits::default()).unwrap();
+    assert_eq!(request.target(), "/1");
+    assert_eq!(consumed, first.len());
+
+    let request = parse(&input[consumed..]).unwrap();
+    assert_eq!(request.target(), "/2");
+}
+
+#[test]
+fn accepts_leading_empty_lines_and_http_1_0_without_host() {
+    let request = parse(b"\r\nGET / HTTP/1.0\r\n\r\n").unwrap();
+
+    assert_eq!(request.url(), "/");
+}
+
+#[test]
+fn absolute_target_is_the_url() {
+    let request = parse(b"GET http://other.example/x HTTP/1.1\r\nHost: a\r\n\r\n").unwrap();
+
+    assert_eq!(request.url(), "http://other.example/x");
+}
+
+#[test]
+fn every_prefix_is_incomplete() {
+    let input =
+        b"POST / HTTP/1.1\r\nHost: a\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n0\r\n\r\n";
+
+    for end in 0..input.len() {
+        assert_eq!(
+            parse(&input[..end]),
+            Err(WireError::Incomplete),
+            "prefix of length {}",
+            end
+        );
+    }
+    assert!(parse(input).is_ok());
+}
+
+#[test]
+fn rejects_malformed_request_lines() {
+    for input in [
+        &b"GET /\r\n\r\n"[..],
+        b"GET  / HTTP/1.1\r\nHost: a\r\n\r\n",
+        b"GET / HTTP/1.1 extra\r\nHost: a\r\n\r\n",
+        b"GET / HTTP/1\r\nHost: a\r\n\r\n",
+        b"GET / FTP/1.1\r\nHost: a\r\n\r\n",
+        b"G(T / HTTP/1.1\r\nHost: a\r\n\r\n",
+        b"GET /\x7f HTTP/1.1\r\nHost: a\r\n\r\n",
+        b"GET / HTTP/1.1\nHost: a\r\n\r\n",
+        b"GET /\xff HTTP/1.1\r\nHost: a\r\n\r\n",
+    ] {
+        assert_eq!(
+            parse(input),
+            Err(WireError::InvalidRequestLine),
+            "{:?}",
+            String::from_utf8_lossy(input)
+        );
+    }
+}
+
+#[test]
+fn rejects_unknown_methods_and_versions() {
+    assert_eq!(
+        parse(b"get / HTTP/1.1\r\nHost: a\r\n\r\n"),
+        Err(WireError::UnknownMethod("get".to_string()))
+    );
+    assert_eq!(
+        parse(b"GET / HTTP/2.0\r\nHost: a\r\n\r\n"),
+        Err(WireError::UnsupportedVersion("HTTP/2.0".to_string()))
+    );
+}
+
+#[test]
+fn rejects_malformed_headers() {
+    for input in [
+        &b"GET / HTTP/1.1\r\nHost: a\r\nNo-Colon\r\n\r\n"[..],
+        b"GET / HTTP/1.1\r\nHost: a\r\nSpace : before\r\n\r\n",
+        b"GET / HTTP/1.1\r\nHost: a\r\n: empty\r\n\r\n",
+        b"GET / HTTP/1.1\r\nHost: a\r\nX: a\r\n folded\r\n\r\n",
+        b"GET / HTTP/1.1\r\nHost: a\r\nX: nul\0\r\n\r\n",
+        b"GET / HTTP/1.1\r\nHost: a\r\nX: bare\rcr\r\n\r\n",
+        b"GET / HTTP/1.1\r\nHost: a\r\nX: bare lf\n\r\n",
+        b"GET / HTTP/1.1\r\nHost: a\r\nX: \xff\r\n\r\n",
+    ] {
+        assert_eq!(
+            parse(input),
+            Err(WireError::InvalidHeader),
+            "{:?}",
+            String::from_utf8_lossy(input)
+        );
+    }
+}
+
+#[test]
+fn rejects_missing_or_repeated_host() {
+    assert_eq!(
+        parse(b"GET / HTTP/1.1\r\n\r\n"),
+        Err(WireError::InvalidHost)
+    );
+    assert_eq!(
+        parse(b"GET / HTTP/1.1\r\nHost: a\r\nhost: b\r\n\r\n"),
+        Err(WireError::InvalidHost)
+    );
+}
+
+#[test]
+fn rejects_ambiguous_framing() {
+    assert_eq!(
+        parse(b"POST / HTTP/1.1\r\nHost: a\r\nContent-Length: 1\r\nTransfer-Encoding: chunked\r\n\r\n0\r\n\r\n"),
+        Err(WireError::AmbiguousLength)
+    );
+    assert_eq!(
+        parse(b"POST / HTTP/1.1\r\nHost: a\r\nContent-Length: 1\r\nContent-Length: 2\r\n\r\nab"),
+        Err(WireError::InvalidContentLength)
+    );
+    assert_eq!(
+        parse(b"POST / HTTP/1.1\r\nHost: a\r\nContent-Length: +1\r\n\r\na"),
+        Err(WireError::InvalidContentLength)
+    );
+    assert_eq!(
+        parse(b"POST / HTTP/1.1\r\nHost: a\r\nTransfer-Encoding: gzip, chunked\r\n\r\n"),
+        Err(WireError::UnsupportedTransferEncoding(
+            "gzip, chunked".to_string()
+        ))
+    );
+}
+
+#[test]
+fn agreeing_content_lengths_are_accepted() {
+    let request =
+        parse(b"POST / HTTP/1.1\r\nHost: a\r\nContent-Length: 2, 2\r\nContent-Length: 2\r\n\r\nab")
+            .unwrap();
+
+    assert_eq!(request.body(), b"ab");
+}
+
+#[test]
+fn rejects_malformed_chunks() {
+    for input in [
+        &b"POST / HTTP/1.1\r\nHost: a\r\nTransfer-Encoding: chunked\r\n\r\nz\r\n"[..],
+        b"POST / HTTP/1.1\r\nHost: a\r\nTransfer-Encoding: chunked\r\n\r\n\r\n",
+        b"POST / HTTP/1.1\r\nHost: a\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabcXY0\r\n\r\n",
+        b"POST / HTTP/1.1\r\nHost: a\r\nTransfer-Encoding: chunked\r\n\r\n11111111111111111\r\n",
+    ] {
+        assert_eq!(
+            parse(input),
+            Err(WireError::InvalidChunk),
+            "{:?}",
+            String::from_utf8_lossy(input)
+        );
+    }
+}
+
+#[test]
+fn enforces_limits() {
+    let limits = WireLimits {
+        max_headers: 2,
+        max_line_size: 32,
+        max_body_size: 4,
+    };
+    let parse = |input: &[u8]| WireRequest::parse(input, &limits).map(|_| ());
+
+    assert_eq!(
+        parse(b"GET / HTTP/1.1\r\nHost: a\r\nA: 1\r\nB: 2\r\n\r\n"),
+        Err(WireError::TooManyHeaders)
+    );
+    assert_eq!(
+        parse(b"GET /a-very-long-path-to-the-resource HTTP/1.1\r\n"),
+        Err(WireError::LineTooLong)
+    );
+    assert_eq!(
+        parse(b"GET / HTTP/1.1\r\nHost: aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"),
+        Err(WireError::LineTooLong)
+    );
+    assert_eq!(
+        parse(b"POST / HTTP/1.1\r\nHost: a\r\nContent-Length: 5\r\n\r\n"),
+        Err(WireError::BodyTooLarge)
+    );
+    assert_eq!(
+        parse(b"POST / HTTP/1.1\r\nHost: a\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n2\r\n"),
+        Err(WireError::BodyTooLarge)
+    );
+    assert_eq!(
+        parse(
+            b"POST / HTTP/1.1\r\nHost: a\r\nTransfer-Encoding: chunked\r\n\r\nffffffffffffffff\r\n"
+        ),
+        Err(WireError::BodyTooLarge)
+    );
+}
+
+#[test]
+fn rejects_overflowing_content_length() {
+    assert_eq!(
+        parse(b"POST / HTTP/1.1\r\nHost: a\r\nContent-Length: 99999999999999999999999\r\n\r\n")
+            .map(|_| ()),
+        Err(WireError::BodyTooLarge)
+    );
+}
+
+#[test]
+fn errors_map_to_statuses() {
+    assert_eq!(WireError::Incomplete.status(), None);
+    assert_eq!(
+        WireError::InvalidHeader.status(),
+        Some(HttpStatusCode::BadRequest)
+    );
+    assert_eq!(
+        WireError::TooManyHeaders.status(),
+        Some(HttpStatusCode::RequestHeaderFieldsTooLarge)
+    );
+    assert_eq!(
+        WireError::BodyTooLarge.status(),
+        Some(HttpStatusCode::PayloadTooLarge)
+    );
+}
+
+#[test]
+fn serializes_response_with_content_length() {
+    let response = ServerResponseBuilder::new()
+        .with_status(HttpStatusCode::Notfound)
+        .with_header("Content-Type".to_string(), "text/plain".to_string())
+        .with_header("Content-Length".to_string(), "999".to_string())
+        .body_str("missing");
+
+    assert_eq!(
+        response.to_wire(BodyEncoding::ContentLength).unwrap(),
+        b"HTTP/1.1 404 Not Found\r\nContent-Type: text/plain\r\nContent-Length: 7\r\n\r\nmissing"
+    );
+}
+
+#[test]
+fn serializes_chunked_response() {
+    let response = ServerResponseBuilder::new().body_str("hello world");
+
+    assert_eq!(
+        response.to_wire(BodyEncoding::Chunked).unwrap(),
+        b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\nB\r\nhello world\r\n0\r\n\r\n"
+    );
+    assert_eq!(
+        ServerResponseBuilder::new()
+            .end()
+            .to_wire(BodyEncoding::Chunked)
+            .unwrap(),
+        b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n0\r\n\r\n"
+    );
+}
+
+#[test]
+fn bodiless_statuses_have_no_framing() {
+    for status in [HttpStatusCode::NoContent, HttpStatusCode::NotModified] {
+        let response = ServerResponseBuilder::new()
+            .with_status(status.clone())
+            .body_str("ignored");
+
+        let expected = format!(
+            "HTTP/1.1 {} {}\r\n\r\n",
+            u16::from(status.clone()),
+            status.reason_phrase()
+        );
+        assert_eq!(
+            response.to_wire(BodyEncoding::ContentLength).unwrap(),
+            expected.as_bytes()
+        );
+    }
+}
+
+#[test]
+fn rejects_header_injection_in_responses() {
+    let response = ServerResponseBuilder::new()
+        .with_header(
+            "X-Injected".to_string(),
+            "a\r\nSet-Cookie: evil".to_string(),
+        )
+        .end();
+
+    assert_eq!(
+        response.to_wire(BodyEncoding::ContentLength),
+        Err(WireError::InvalidHeader)
+    );
+}
+
+fn parse_response(input: &[u8], method: HttpMethod) -> Result<WireResponse, WireError> {
+    WireResponse::parse(input, &WireLimits::default(), method).map(|(response, _)| response)
+}
+
+#[test]
+fn parses_response_with_content_length() {
+    let input = b"HTTP/1.1 201 Created\r\nSet-Cookie: a=1\r\nSet-Cookie: b=2\r\nContent-Length: 5\r\n\r\nhelloextra";
+
+    let (response, consumed) =
+        WireResponse::parse(input, &WireLimits::default(), HttpMethod::Post).unwrap();
+
+    assert_eq!(consumed, input.len() - 5);
+    assert_eq!(response.status(), HttpStatusCode::Created);
+    assert_eq!(response.body(), b"hello");
+    let cookies: Vec<_> = response
+        .headers()
+        .iter()
+        .filter(|(name, _)| name == "Set-Cookie")
+        .map(|(_, value)| value.as_str())
+        .collect();
+    assert_eq!(cookies, ["a=1", "b=2"]);
+}
+
+#[test]
+fn parses_chunked_and_unframed_responses() {
+    let chunked =
+        b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n2\r\nde\r\n0\r\n\r\n";
+    assert_eq!(
+        parse_response(chunked, HttpMethod::Get).unwrap().body(),
+        b"abcde"
+    );
+
+    let unframed = b"HTTP/1.0 200 OK\r\n\r\nuntil the end";
+    assert_eq!(
+        parse_response(unframed, HttpMethod::Get).unwrap().body(),
+        b"until the end"
+    );
+}
+
+#[test]
+fn skips_interim_responses() {
+    let input = b"HTTP/1.1 100 Continue\r\n\r\nHTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok";
+
+    let response = parse_response(input, HttpMethod::Post).unwrap();
+
+    assert_eq!(response.status(), HttpStatusCode::Ok);
+    assert_eq!(response.body(), b"ok");
+}
+
+#[test]
+fn head_and_bodiless_responses_ignore_framing() {
+    let head = b"HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\n";
+    assert!(parse_response(head, HttpMethod::Head)
+        .unwrap()
+        .body()
+        .is_empty());
+
+    let no_content = b"HTTP/1.1 204 No Content\r\n\r\n";
+    assert!(parse_response(no_content, HttpMethod::Get)
+        .unwrap()
+        .body()
+        .is_empty());
+}
+
+#[test]
+fn rejects_malformed_responses() {
+    assert_eq!(
+        parse_response(
+            b"HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\nshort",
+            HttpMethod::Get
+        ),
+        Err(WireError::Incomplete)
+    );
+    assert_eq!(
+        parse_response(b"HTTP/1.1 abc OK\r\n\r\n", HttpMethod::Get),
+        Err(WireError::InvalidStatusLine)
+    );
+    assert_eq!(
+        parse_response(b"SPDY/3 200 OK\r\n\r\n", HttpMethod::Get),
+        Err(WireError::InvalidStatusLine)
+    );
+    assert_eq!(
+        parse_response(b"HTTP/1.1 599 Whatever\r\n\r\n", HttpMethod::Get),
+        Err(WireError::UnknownStatus(599))
+    );
+}
+
+#[test]
+fn serialized_responses_parse_back() {
+    let response = ServerResponseBuilder::new()
+        .with_status(HttpStatusCode::Accepted)
+        .with_header("X-Test".to_string(), "1".to_string())
+        .body_str("payload");
+
+    for encoding in [BodyEncoding::ContentLength, BodyEncoding::Chunked] {
+        let wire = response.to_wire(encoding).unwrap();
+        let parsed = parse_response(&wire, HttpMethod::Get).unwrap();
+
+        assert_eq!(parsed.status(), HttpStatusCode::Accepted);
+        assert_eq!(parsed.body(), b"payload");
+        assert!(parsed
+            .headers()
+            .iter()
+            .any(|(name, value)| name == "X-Test" && value == "1"));
+    }
+}
+
+fn header_value() -> impl Strategy<Value = String> {
+    "[!-~]([ !-~]{0,8}[!-~])?"
+}
+
+proptest! {
+    #[test]
+    fn parse_never_panics(input in prop::collection::vec(any::<u8>(), 0..256)) {
+        let _ = WireRequest::parse(&input, &WireLimits::default());
+    }
+
+    #[test]
+    fn parse_never_panics_after_valid_head(tail in prop::collection::vec(any::<u8>(), 0..128)) {
+        let input = [&b"POST / HTTP/1.1\r\nHost: a\r\nTransfer-Encoding: chunked\r\n\r\n"[..], &tail].concat();
+        let _ = WireRequest::parse(&input, &WireLimits::default());
+    }
+
+    #[test]
+    fn request_round_trips(
+        headers in prop::collection::vec(("[A-Za-z][A-Za-z0-9-]{0,8}", header_value()), 0..8),
+        body in prop::collection::vec(any::<u8>(), 0..64),
+        chunked in any::<bool>(),
+    ) {
+        let headers = headers
+            .into_iter()
+            .filter(|(name, _)| {
+                !["Host", "Content-Length", "Transfer-Encoding"]
+                    .iter()
+                    .any(|framing| framing.eq_ignore_ascii_case(name))
+            })
+            .collect::<Vec<_>>();
+        let mut input = b"PATCH /round/trip HTTP/1.1\r\nHost: example.com\r\n".to_vec();
+        for (name, value) in &headers {
+            input.extend_from_slice(format!("{}: {}\r\n", name, value).as_bytes());
+        }
+        if chunked {
+            input.extend_from_slice(b"Transfer-Encoding: chunked\r\n\r\n");
+            for chunk in body.chunks(7) {
+                input.extend_from_slice(format!("{:x}\r\n", chunk.len()).as_bytes());
+                input.extend_from_slice(chunk);
+                input.extend_from_slice(b"\r\n");
+            }
+            input.extend_from_slice(b"0\r\n\r\n");
+        } else {
+            input.extend_from_slice(format!("Content-Length: {}\r\n\r\n", body.len()).as_bytes());
+            input.extend_from_slice(&body);
+        }
+
+        let (request, consumed) = WireRequest::parse(&input, &WireLimits::default()).unwrap();
+
+        prop_assert_eq!(consumed, input.len());
+        prop_assert_eq!(request.method(), HttpMethod::Patch);
+        prop_assert_eq!(request.url(), "http://example.com/round/trip");
+        prop_assert_eq!(&request.headers()[1..=headers.len()], &headers[..]);
+        prop_assert_eq!(request.body(), &body[..]);
+    }
+}